@@ -0,0 +1,174 @@
+use std::ops;
+use std::slice::SliceIndex;
+use std::str;
+
+use crate::error::GetManyError;
+use crate::overlap::{IndexOverlap, IndexSpan, Span};
+use crate::{ManyIndices, SliceSingleIndex};
+
+/* -------------------------------------------------------------------------- */
+
+/// Marks an index as borrowing its items immutably.
+///
+/// Shared indices may overlap each other, but not a [`Mut`] index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Shared<I>(pub I);
+
+/// Marks an index as borrowing its items mutably.
+///
+/// Used alongside [`Shared`] indices, since markers only check their overlap against other markers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Mut<I>(pub I);
+
+/* -------------------------------------------------------------------------- */
+
+unsafe impl<I, J> IndexOverlap<Shared<J>> for Shared<I> {
+    #[inline]
+    fn overlap(&self, _other: &Shared<J>) -> bool {
+        // NOTE: items may be borrowed immutably many times.
+        false
+    }
+}
+
+unsafe impl<I, J> IndexOverlap<Mut<J>> for Shared<I>
+where
+    I: IndexOverlap<J>,
+{
+    #[inline]
+    fn overlap(&self, other: &Mut<J>) -> bool {
+        self.0.overlap(&other.0)
+    }
+}
+
+unsafe impl<I, J> IndexOverlap<Shared<J>> for Mut<I>
+where
+    I: IndexOverlap<J>,
+{
+    #[inline]
+    fn overlap(&self, other: &Shared<J>) -> bool {
+        self.0.overlap(&other.0)
+    }
+}
+
+// NOTE: `Shared` does not implement `IndexSpan` since, unlike the other indices,
+// it does not overlap everything its span intersects. `Mut` does, which also makes it
+// overlap other `Mut` indices through its span.
+unsafe impl<I: IndexSpan> IndexSpan for Mut<I> {
+    #[inline]
+    fn span(&self) -> Span {
+        self.0.span()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+macro_rules! impl_many_indices {
+    ( $($Marker:ident)* ) => {$(
+        unsafe impl<I: ManyIndices> ManyIndices for $Marker<I> {
+            fn is_disjoint(&self) -> bool {
+                self.0.is_disjoint()
+            }
+
+            fn is_in_bounds(&self, bounds: ops::RangeTo<usize>) -> bool {
+                self.0.is_in_bounds(bounds)
+            }
+
+            fn is_disjoint_and_in_bounds(&self, bounds: ops::RangeTo<usize>) -> bool {
+                self.0.is_disjoint_and_in_bounds(bounds)
+            }
+
+            fn check_in_bounds(&self, bounds: ops::RangeTo<usize>) -> Result<(), GetManyError> {
+                self.0.check_in_bounds(bounds)
+            }
+
+            fn check_disjoint_and_in_bounds(
+                &self,
+                bounds: ops::RangeTo<usize>,
+            ) -> Result<(), GetManyError> {
+                self.0.check_disjoint_and_in_bounds(bounds)
+            }
+
+            fn check_char_boundaries(&self, s: &str) -> Result<(), GetManyError> {
+                self.0.check_char_boundaries(s)
+            }
+
+            fn fmt_index(&self) -> Option<String> {
+                let index = self.0.fmt_index()?;
+                Some(format!("{}({index})", stringify!($Marker)))
+            }
+        }
+    )*};
+}
+
+impl_many_indices!(Shared Mut);
+
+/* -------------------------------------------------------------------------- */
+
+unsafe impl<'slice, T: 'slice, I> SliceSingleIndex<'slice, [T]> for Shared<I>
+where
+    I: SliceIndex<[T], Output: 'slice>,
+{
+    type Output = &'slice I::Output;
+
+    #[inline]
+    unsafe fn get_unchecked_raw(self, slice: *mut [T]) -> Self::Output {
+        // NOTE: never create a mutable reference, other shared indices may borrow the same items.
+        let slice = slice as *const [T];
+
+        // SAFETY: contract uphold by the caller.
+        unsafe { &*slice.get_unchecked(self.0) }
+    }
+}
+
+unsafe impl<'slice, I> SliceSingleIndex<'slice, str> for Shared<I>
+where
+    I: SliceIndex<str, Output = str> + SliceIndex<[u8], Output = [u8]>,
+{
+    type Output = &'slice str;
+
+    #[inline]
+    unsafe fn get_unchecked_raw(self, slice: *mut str) -> Self::Output {
+        let bytes = slice as *const [u8];
+
+        // SAFETY: contract uphold by the caller, the indices lie on char boundaries.
+        unsafe { str::from_utf8_unchecked(&*bytes.get_unchecked(self.0)) }
+    }
+}
+
+unsafe impl<'slice, Slice: ?Sized, I> SliceSingleIndex<'slice, Slice> for Mut<I>
+where
+    I: SliceSingleIndex<'slice, Slice>,
+{
+    type Output = I::Output;
+
+    #[inline]
+    unsafe fn get_unchecked_raw(self, slice: *mut Slice) -> Self::Output {
+        // SAFETY: contract uphold by the caller.
+        unsafe { self.0.get_unchecked_raw(slice) }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use crate::{DisjointIndices, ManyIndices};
+
+    use super::{Mut, Shared};
+
+    #[test]
+    fn shared_overlap() {
+        assert!((Shared(2), Shared(2), Shared(1..4)).is_disjoint());
+        assert!([Shared(..), Shared(..)].is_disjoint());
+        assert!((Mut(0), Shared(1), Shared(1)).is_disjoint());
+        assert!((Mut(0..2), Shared(2..4), Mut(4..)).is_disjoint());
+
+        assert!(!(Mut(2), Shared(2)).is_disjoint());
+        assert!(!(Shared(1..4), Mut(3)).is_disjoint());
+        assert!(!(Mut(0..3), Shared(5), Mut(2)).is_disjoint());
+
+        assert!(DisjointIndices::new((Mut(0), Shared(3), Shared(3))).is_some());
+    }
+}
+
+/* -------------------------------------------------------------------------- */
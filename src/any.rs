@@ -0,0 +1,168 @@
+use std::ops::{
+    self, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
+};
+use std::slice;
+
+use crate::error::GetManyError;
+use crate::overlap::{IndexSpan, Span};
+use crate::{ManyIndices, ShiftIndices, SliceSingleIndex};
+
+/* -------------------------------------------------------------------------- */
+
+/// Any single index, so that a dynamic set of indices may mix items and ranges.
+///
+/// ```
+/// # use slice_get_many_poc::{AnyIndex, AnyOutput, SliceExt};
+/// let mut array = [0, 1, 2, 3, 4, 5];
+///
+/// let indices = vec![AnyIndex::from(0), AnyIndex::from(2..4), AnyIndex::from(5..)];
+/// let parts = array.get_many_mut_poc(indices).unwrap();
+///
+/// assert_eq!(parts[0], AnyOutput::Item(&mut 0));
+/// assert_eq!(parts[1], AnyOutput::Slice(&mut [2, 3]));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AnyIndex {
+    Item(usize),
+    Range(Range<usize>),
+    RangeInclusive(RangeInclusive<usize>),
+    RangeFrom(RangeFrom<usize>),
+    RangeTo(RangeTo<usize>),
+    RangeToInclusive(RangeToInclusive<usize>),
+    RangeFull,
+}
+
+/// The reference to the item(s) of an [`AnyIndex`].
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub enum AnyOutput<'slice, T> {
+    Item(&'slice mut T),
+    Slice(&'slice mut [T]),
+}
+
+impl<'slice, T> AnyOutput<'slice, T> {
+    /// Returns the item, or [`None`] if this is a slice.
+    pub fn into_item(self) -> Option<&'slice mut T> {
+        match self {
+            Self::Item(item) => Some(item),
+            Self::Slice(_) => None,
+        }
+    }
+
+    /// Returns the slice, a single item being a slice of length 1.
+    pub fn into_slice(self) -> &'slice mut [T] {
+        match self {
+            Self::Item(item) => slice::from_mut(item),
+            Self::Slice(slice) => slice,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+macro_rules! impl_from {
+    ( $( $Variant:ident($I:ty) ),* ) => {$(
+        impl From<$I> for AnyIndex {
+            fn from(index: $I) -> Self {
+                Self::$Variant(index)
+            }
+        }
+    )*};
+}
+
+impl_from!(
+    Item(usize),
+    Range(Range<usize>),
+    RangeInclusive(RangeInclusive<usize>),
+    RangeFrom(RangeFrom<usize>),
+    RangeTo(RangeTo<usize>),
+    RangeToInclusive(RangeToInclusive<usize>)
+);
+
+impl From<RangeFull> for AnyIndex {
+    fn from(_: RangeFull) -> Self {
+        Self::RangeFull
+    }
+}
+
+/// Calls `$f` with the index wrapped by `$index`.
+macro_rules! with_index {
+    ($index:expr, $idx:ident => $f:expr) => {
+        match $index {
+            AnyIndex::Item($idx) => $f,
+            AnyIndex::Range($idx) => $f,
+            AnyIndex::RangeInclusive($idx) => $f,
+            AnyIndex::RangeFrom($idx) => $f,
+            AnyIndex::RangeTo($idx) => $f,
+            AnyIndex::RangeToInclusive($idx) => $f,
+            AnyIndex::RangeFull => {
+                let $idx = ..;
+                $f
+            }
+        }
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+unsafe impl ManyIndices for AnyIndex {
+    fn is_disjoint(&self) -> bool {
+        true
+    }
+
+    fn is_in_bounds(&self, bounds: ops::RangeTo<usize>) -> bool {
+        with_index!(self, idx => idx.is_in_bounds(bounds))
+    }
+
+    fn check_in_bounds(&self, bounds: ops::RangeTo<usize>) -> Result<(), GetManyError> {
+        with_index!(self, idx => idx.check_in_bounds(bounds))
+    }
+
+    fn check_char_boundaries(&self, s: &str) -> Result<(), GetManyError> {
+        with_index!(self, idx => idx.check_char_boundaries(s))
+    }
+
+    fn fmt_index(&self) -> Option<String> {
+        with_index!(self, idx => idx.fmt_index())
+    }
+}
+
+unsafe impl IndexSpan for AnyIndex {
+    #[inline]
+    fn span(&self) -> Span {
+        with_index!(self, idx => idx.span())
+    }
+}
+
+unsafe impl<'slice, T: 'slice> SliceSingleIndex<'slice, [T]> for AnyIndex {
+    type Output = AnyOutput<'slice, T>;
+
+    #[inline]
+    unsafe fn get_unchecked_raw(self, slice: *mut [T]) -> Self::Output {
+        // SAFETY: contract uphold by the caller.
+        unsafe {
+            match self {
+                AnyIndex::Item(idx) => AnyOutput::Item(idx.get_unchecked_raw(slice)),
+                AnyIndex::Range(idx) => AnyOutput::Slice(idx.get_unchecked_raw(slice)),
+                AnyIndex::RangeInclusive(idx) => AnyOutput::Slice(idx.get_unchecked_raw(slice)),
+                AnyIndex::RangeFrom(idx) => AnyOutput::Slice(idx.get_unchecked_raw(slice)),
+                AnyIndex::RangeTo(idx) => AnyOutput::Slice(idx.get_unchecked_raw(slice)),
+                AnyIndex::RangeToInclusive(idx) => AnyOutput::Slice(idx.get_unchecked_raw(slice)),
+                AnyIndex::RangeFull => AnyOutput::Slice((..).get_unchecked_raw(slice)),
+            }
+        }
+    }
+}
+
+impl ShiftIndices for AnyIndex {
+    type Output = AnyIndex;
+
+    fn offset(self, k: usize) -> Option<Self::Output> {
+        with_index!(self, idx => idx.offset(k).map(AnyIndex::from))
+    }
+
+    fn rebase<R: RangeBounds<usize>>(self, range: R) -> Option<Self::Output> {
+        with_index!(self, idx => idx.rebase(range).map(AnyIndex::from))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,100 @@
+use std::ops;
+
+use crate::error::GetManyError;
+use crate::overlap::{IndexSpan, Span};
+use crate::{ManyIndices, SliceSingleIndex};
+
+/* -------------------------------------------------------------------------- */
+
+/// A user-defined index, which is used as the index it converts to.
+///
+/// It overlaps other indices through the span of that index.
+///
+/// ```
+/// # use slice_get_many_poc::{AsIndex, SliceExt};
+/// struct NodeId(u32);
+///
+/// unsafe impl AsIndex for NodeId {
+///     type Index = usize;
+///
+///     fn as_index(&self) -> usize {
+///         self.0 as usize
+///     }
+/// }
+///
+/// let mut nodes = ["a", "b", "c"];
+/// let [a, c] = nodes.get_many_mut_poc([NodeId(0), NodeId(2)]).unwrap();
+/// ```
+///
+/// # Safety
+///
+/// `as_index` must always return the same index for the same value,
+/// since the index is checked and used separately.
+pub unsafe trait AsIndex {
+    type Index;
+
+    fn as_index(&self) -> Self::Index;
+}
+
+/* -------------------------------------------------------------------------- */
+
+unsafe impl<I> ManyIndices for I
+where
+    I: AsIndex<Index: ManyIndices>,
+{
+    fn is_disjoint(&self) -> bool {
+        self.as_index().is_disjoint()
+    }
+
+    fn is_in_bounds(&self, bounds: ops::RangeTo<usize>) -> bool {
+        self.as_index().is_in_bounds(bounds)
+    }
+
+    fn is_disjoint_and_in_bounds(&self, bounds: ops::RangeTo<usize>) -> bool {
+        self.as_index().is_disjoint_and_in_bounds(bounds)
+    }
+
+    fn check_in_bounds(&self, bounds: ops::RangeTo<usize>) -> Result<(), GetManyError> {
+        self.as_index().check_in_bounds(bounds)
+    }
+
+    fn check_disjoint_and_in_bounds(
+        &self,
+        bounds: ops::RangeTo<usize>,
+    ) -> Result<(), GetManyError> {
+        self.as_index().check_disjoint_and_in_bounds(bounds)
+    }
+
+    fn check_char_boundaries(&self, s: &str) -> Result<(), GetManyError> {
+        self.as_index().check_char_boundaries(s)
+    }
+
+    fn fmt_index(&self) -> Option<String> {
+        self.as_index().fmt_index()
+    }
+}
+
+unsafe impl<I> IndexSpan for I
+where
+    I: AsIndex<Index: IndexSpan>,
+{
+    #[inline]
+    fn span(&self) -> Span {
+        self.as_index().span()
+    }
+}
+
+unsafe impl<'slice, Slice: ?Sized, I> SliceSingleIndex<'slice, Slice> for I
+where
+    I: AsIndex<Index: SliceSingleIndex<'slice, Slice>>,
+{
+    type Output = <I::Index as SliceSingleIndex<'slice, Slice>>::Output;
+
+    #[inline]
+    unsafe fn get_unchecked_raw(self, slice: *mut Slice) -> Self::Output {
+        // SAFETY: contract uphold by the caller, on the same index that has been checked.
+        unsafe { self.as_index().get_unchecked_raw(slice) }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
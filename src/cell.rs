@@ -0,0 +1,205 @@
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut, Range, RangeBounds};
+use std::ptr::NonNull;
+use std::slice;
+
+use crate::overlap::IndexOverlap;
+
+/* -------------------------------------------------------------------------- */
+
+/// A mutable slice whose regions are borrowed at runtime, like a [`RefCell`] for each region.
+///
+/// Many regions can be borrowed immutably at the same time, but a region borrowed mutably
+/// may not overlap any other live borrow.
+pub struct SliceCell<'a, T> {
+    slice: NonNull<[T]>,
+    borrows: RefCell<Vec<Borrow>>,
+    _marker: PhantomData<&'a mut [T]>,
+}
+
+/// A live borrow of a region of a [`SliceCell`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Borrow {
+    range: Range<usize>,
+    mutable: bool,
+}
+
+impl<'a, T> SliceCell<'a, T> {
+    pub fn new(slice: &'a mut [T]) -> Self {
+        Self {
+            slice: NonNull::from(slice),
+            borrows: RefCell::new(Vec::new()),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the length of the underlying slice.
+    pub fn len(&self) -> usize {
+        self.slice.len()
+    }
+
+    /// Returns `true` if the underlying slice is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Immutably borrows the items in `range`,
+    /// or returns [`None`] if it is out of bounds or overlaps a live mutable borrow.
+    pub fn try_borrow<R>(&self, range: R) -> Option<SliceRef<'_, T>>
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = self.acquire(range, false)?;
+
+        // SAFETY: `range` is in bounds and no live mutable borrow overlaps it.
+        let items = unsafe { &*self.slice.as_ptr().get_unchecked_mut(range.clone()) };
+
+        Some(SliceRef {
+            items,
+            _borrow: BorrowGuard {
+                borrows: &self.borrows,
+                borrow: Borrow {
+                    range,
+                    mutable: false,
+                },
+            },
+        })
+    }
+
+    /// Mutably borrows the items in `range`,
+    /// or returns [`None`] if it is out of bounds or overlaps a live borrow.
+    pub fn try_borrow_mut<R>(&self, range: R) -> Option<SliceRefMut<'_, T>>
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = self.acquire(range, true)?;
+
+        // SAFETY: `range` is in bounds and no live borrow overlaps it.
+        let items = unsafe { &mut *self.slice.as_ptr().get_unchecked_mut(range.clone()) };
+
+        Some(SliceRefMut {
+            items,
+            _borrow: BorrowGuard {
+                borrows: &self.borrows,
+                borrow: Borrow {
+                    range,
+                    mutable: true,
+                },
+            },
+        })
+    }
+
+    /// Records a borrow of `range` if it is in bounds and does not conflict with a live borrow.
+    fn acquire<R>(&self, range: R, mutable: bool) -> Option<Range<usize>>
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = slice::try_range(range, ..self.len())?;
+        let mut borrows = self.borrows.borrow_mut();
+
+        let conflict = borrows
+            .iter()
+            .any(|b| (mutable || b.mutable) && range.overlap(&b.range));
+
+        if conflict {
+            return None;
+        }
+
+        borrows.push(Borrow {
+            range: range.clone(),
+            mutable,
+        });
+
+        Some(range)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Releases a borrow of a [`SliceCell`] when dropped.
+struct BorrowGuard<'b> {
+    borrows: &'b RefCell<Vec<Borrow>>,
+    borrow: Borrow,
+}
+
+impl Drop for BorrowGuard<'_> {
+    fn drop(&mut self) {
+        let mut borrows = self.borrows.borrow_mut();
+
+        // NOTE: identical borrows are interchangeable, so we can release any of them.
+        if let Some(i) = borrows.iter().position(|b| *b == self.borrow) {
+            borrows.swap_remove(i);
+        }
+    }
+}
+
+/// An immutable borrow of a region of a [`SliceCell`].
+pub struct SliceRef<'b, T> {
+    items: &'b [T],
+    _borrow: BorrowGuard<'b>,
+}
+
+impl<T> Deref for SliceRef<'_, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.items
+    }
+}
+
+/// A mutable borrow of a region of a [`SliceCell`].
+pub struct SliceRefMut<'b, T> {
+    items: &'b mut [T],
+    _borrow: BorrowGuard<'b>,
+}
+
+impl<T> Deref for SliceRefMut<'_, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.items
+    }
+}
+
+impl<T> DerefMut for SliceRefMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.items
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::SliceCell;
+
+    #[test]
+    fn borrow_regions() {
+        let mut array = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let cell = SliceCell::new(&mut array);
+
+        let mut a = cell.try_borrow_mut(..3).unwrap();
+        let b = cell.try_borrow(5..).unwrap();
+        let c = cell.try_borrow(4..=6).unwrap();
+
+        assert!(cell.try_borrow_mut(2..4).is_none());
+        assert!(cell.try_borrow(..1).is_none());
+        assert!(cell.try_borrow_mut(6..7).is_none());
+        assert!(cell.try_borrow(8..11).is_none());
+
+        a[0] = b[0] + c[0];
+
+        drop(a);
+        assert!(cell.try_borrow(..1).is_some());
+
+        drop((b, c));
+        let mut d = cell.try_borrow_mut(..).unwrap();
+        d[9] = 90;
+        drop(d);
+
+        assert_eq!(array, [9, 1, 2, 3, 4, 5, 6, 7, 8, 90]);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,121 @@
+use std::ops::Range;
+
+use crate::overlap::{spans_overlap, Span};
+
+/* -------------------------------------------------------------------------- */
+
+/// Asserts that the indices in `I` are disjoint and in bounds of any array of length `LEN`.
+///
+/// Created with the [`const_indices!`] macro, which checks the indices at compile time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ConstIndices<I, const LEN: usize>(I);
+
+impl<I, const LEN: usize> ConstIndices<I, LEN> {
+    /// # Safety
+    ///
+    /// All indices in `I` must be disjoint and in bounds of `..LEN`.
+    pub unsafe fn new_unchecked(indices: I) -> Self {
+        Self(indices)
+    }
+
+    /// Checks the indices through their `spans` then wraps them.
+    ///
+    /// This is an implementation detail of [`const_indices!`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the spans are not disjoint or not in bounds of `..LEN`.
+    ///
+    /// # Safety
+    ///
+    /// `spans` must contain the span of items borrowed by each index of `indices`.
+    #[doc(hidden)]
+    pub const unsafe fn from_spans(indices: I, spans: &[Range<usize>]) -> Self {
+        let mut i = 0;
+        while i < spans.len() {
+            let a = &spans[i];
+
+            if a.start > a.end || a.end > LEN {
+                panic!("the indices are not in bounds");
+            }
+
+            let mut j = 0;
+            while j < i {
+                if spans_overlap(&Span::from_range(a), &Span::from_range(&spans[j])) {
+                    panic!("the indices are not disjoint");
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+
+        Self(indices)
+    }
+
+    pub fn get(self) -> I {
+        self.0
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`ConstIndices`] from an array or a tuple of literal indices,
+/// whose disjointness and bounds are checked at compile time against the length of the indexed array.
+///
+/// Each index is either a literal `i`, a range `a..b` or an inclusive range `a..=b`.
+///
+/// ```
+/// # use slice_get_many_poc::{const_indices, ArrayExt};
+/// let mut array = [0, 1, 2, 3, 4, 5, 6, 7];
+///
+/// let [a, b] = array.get_many_mut_const(const_indices!([1, 6]));
+/// let (c, d) = array.get_many_mut_const(const_indices!((0, 4..=7)));
+/// ```
+///
+/// Indices out of the bounds of the array fail to compile:
+///
+/// ```compile_fail
+/// # use slice_get_many_poc::{const_indices, ArrayExt};
+/// let mut array = [0, 1, 2, 3, 4, 5, 6, 7];
+/// let [a, b] = array.get_many_mut_const(const_indices!([1, 8]));
+/// ```
+///
+/// So do overlapping indices:
+///
+/// ```compile_fail
+/// # use slice_get_many_poc::{const_indices, ArrayExt};
+/// let mut array = [0, 1, 2, 3, 4, 5, 6, 7];
+/// let (a, b) = array.get_many_mut_const(const_indices!((5, 2..6)));
+/// ```
+#[macro_export]
+macro_rules! const_indices {
+    ([ $( $start:literal $(.. $end:literal)? $(..= $end_incl:literal)? ),* $(,)? ]) => {
+        // SAFETY: the spans are built from the indices themselves.
+        const {
+            unsafe {
+                $crate::ConstIndices::from_spans(
+                    [ $( $start $(.. $end)? $(..= $end_incl)? ),* ],
+                    &[ $( $crate::const_indices!(@span $start $(.. $end)? $(..= $end_incl)?) ),* ],
+                )
+            }
+        }
+    };
+
+    (( $( $start:literal $(.. $end:literal)? $(..= $end_incl:literal)? ),* $(,)? )) => {
+        // SAFETY: the spans are built from the indices themselves.
+        const {
+            unsafe {
+                $crate::ConstIndices::from_spans(
+                    ( $( $start $(.. $end)? $(..= $end_incl)? ,)* ),
+                    &[ $( $crate::const_indices!(@span $start $(.. $end)? $(..= $end_incl)?) ),* ],
+                )
+            }
+        }
+    };
+
+    (@span $index:literal) => { $index..$index + 1 };
+    (@span $start:literal .. $end:literal) => { $start..$end };
+    (@span $start:literal ..= $end:literal) => { $start..$end + 1 };
+}
+
+/* -------------------------------------------------------------------------- */
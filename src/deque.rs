@@ -0,0 +1,199 @@
+use std::collections::VecDeque;
+use std::ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+use crate::error::GetManyError;
+use crate::overlap::IndexSpan;
+use crate::slice_index::RawManyIndices;
+use crate::{get_many_fail, ManyIndices, SliceSingleIndex};
+
+/* -------------------------------------------------------------------------- */
+
+/// A view of the storage of a [`VecDeque`], as the two slices returned by [`VecDeque::as_mut_slices`].
+///
+/// Indices into it are positions in the deque: they go through the front slice, then through the back one.
+/// Since the storage of a deque may wrap around, a range yields a pair of slices, the second one being empty
+/// if the range does not straddle the wrap.
+///
+/// It is not exported: it only lives for the duration of a call to [`VecDequeExt`],
+/// and is only handed to the sets of indices of this crate.
+pub struct DequeSlices<T> {
+    front: *mut [T],
+    back: *mut [T],
+}
+
+/* -------------------------------------------------------------------------- */
+
+unsafe impl<'slice, T: 'slice> SliceSingleIndex<'slice, DequeSlices<T>> for usize {
+    type Output = &'slice mut T;
+
+    #[inline]
+    unsafe fn get_unchecked_raw(self, slice: *mut DequeSlices<T>) -> Self::Output {
+        // SAFETY: contract uphold by the caller.
+        unsafe {
+            let DequeSlices { front, back } = *slice;
+
+            if self < front.len() {
+                &mut *front.get_unchecked_mut(self)
+            } else {
+                &mut *back.get_unchecked_mut(self - front.len())
+            }
+        }
+    }
+}
+
+macro_rules! impl_range_index {
+    ( $($I:ty),* ) => {$(
+        unsafe impl<'slice, T: 'slice> SliceSingleIndex<'slice, DequeSlices<T>> for $I {
+            type Output = (&'slice mut [T], &'slice mut [T]);
+
+            #[inline]
+            unsafe fn get_unchecked_raw(self, slice: *mut DequeSlices<T>) -> Self::Output {
+                // SAFETY: contract uphold by the caller.
+                unsafe {
+                    let DequeSlices { front, back } = *slice;
+                    let len = front.len() + back.len();
+                    let (start, end) = split_span(self.span().to_range(len), front.len());
+
+                    (
+                        &mut *front.get_unchecked_mut(start),
+                        &mut *back.get_unchecked_mut(end),
+                    )
+                }
+            }
+        }
+    )*};
+}
+
+impl_range_index!(
+    Range<usize>,
+    RangeInclusive<usize>,
+    RangeFrom<usize>,
+    RangeTo<usize>,
+    RangeToInclusive<usize>,
+    RangeFull,
+    (Bound<usize>, Bound<usize>)
+);
+
+/// Splits the items of an in-bounds range into their parts in the front and in the back slices.
+fn split_span(range: Range<usize>, front: usize) -> (Range<usize>, Range<usize>) {
+    let Range { start, end } = range;
+
+    (
+        start.min(front)..end.min(front),
+        start.saturating_sub(front)..end.saturating_sub(front),
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An extension trait to add the `get_many_mut` methods to [`VecDeque`].
+///
+/// Items are indexed by their position in the deque.
+/// Range indices yield a pair of slices, as returned by [`VecDeque::as_mut_slices`],
+/// so they do not require the deque to be made contiguous.
+///
+/// The sets of indices of this crate (arrays, tuples, `Vec`, slices and their wrappers) can be used,
+/// but not user-defined implementations of [`SliceManyIndices`](crate::SliceManyIndices).
+pub trait VecDequeExt<T> {
+    /// Returns mutable references to many indices at once,
+    /// or [`None`] if `indices` is not disjoint or not in bounds.
+    fn get_many_mut_poc<'deque, I>(&'deque mut self, indices: I) -> Option<I::Output>
+    where
+        I: ManyIndices + RawManyIndices<'deque, DequeSlices<T>>;
+
+    /// Returns mutable references to many indices at once,
+    /// or a [`GetManyError`] describing why `indices` is not disjoint or not in bounds.
+    fn try_get_many_mut_poc<'deque, I>(
+        &'deque mut self,
+        indices: I,
+    ) -> Result<I::Output, GetManyError>
+    where
+        I: ManyIndices + RawManyIndices<'deque, DequeSlices<T>>;
+
+    /// Returns mutable references to many indices at once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `indices` is not disjoint or not in bounds,
+    /// with a message describing the offending indices.
+    #[track_caller]
+    fn index_many_mut_poc<'deque, I>(&'deque mut self, indices: I) -> I::Output
+    where
+        I: ManyIndices + RawManyIndices<'deque, DequeSlices<T>>,
+    {
+        match self.try_get_many_mut_poc(indices) {
+            Ok(output) => output,
+            Err(err) => get_many_fail(err),
+        }
+    }
+
+    /// Returns mutable references to many indices at once, without doing any checks.
+    ///
+    /// For a safe alternative see [`get_many_mut_poc`](VecDequeExt::get_many_mut_poc).
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with overlapping or out-of-bounds indices is *undefined behavior*
+    /// even if the resulting references are not used.
+    unsafe fn get_many_mut_unchecked_poc<'deque, I>(&'deque mut self, indices: I) -> I::Output
+    where
+        I: RawManyIndices<'deque, DequeSlices<T>>;
+}
+
+impl<T> VecDequeExt<T> for VecDeque<T> {
+    fn get_many_mut_poc<'deque, I>(&'deque mut self, indices: I) -> Option<I::Output>
+    where
+        I: ManyIndices + RawManyIndices<'deque, DequeSlices<T>>,
+    {
+        if !indices.is_disjoint_and_in_bounds(..self.len()) {
+            return None;
+        }
+
+        // SAFETY: we have checked that `indices` is disjoint and in bounds.
+        Some(unsafe { self.get_many_mut_unchecked_poc(indices) })
+    }
+
+    fn try_get_many_mut_poc<'deque, I>(
+        &'deque mut self,
+        indices: I,
+    ) -> Result<I::Output, GetManyError>
+    where
+        I: ManyIndices + RawManyIndices<'deque, DequeSlices<T>>,
+    {
+        indices.check_disjoint_and_in_bounds(..self.len())?;
+
+        // SAFETY: we have checked that `indices` is disjoint and in bounds.
+        Ok(unsafe { self.get_many_mut_unchecked_poc(indices) })
+    }
+
+    unsafe fn get_many_mut_unchecked_poc<'deque, I>(&'deque mut self, indices: I) -> I::Output
+    where
+        I: RawManyIndices<'deque, DequeSlices<T>>,
+    {
+        let (front, back) = self.as_mut_slices();
+
+        // NOTE: the view itself is a local, only the slices it points to live for `'deque`.
+        let mut slices = DequeSlices { front, back };
+
+        // SAFETY: the slices are borrowed from `self` for `'deque`,
+        // and the caller ensures that `indices` is disjoint and in bounds.
+        unsafe { RawManyIndices::get_many_unchecked_raw(indices, &mut slices) }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use super::split_span;
+
+    #[test]
+    fn split_spans() {
+        assert_eq!(split_span(0..3, 4), (0..3, 0..0));
+        assert_eq!(split_span(2..7, 4), (2..4, 0..3));
+        assert_eq!(split_span(5..8, 4), (4..4, 1..4));
+        assert_eq!(split_span(4..4, 4), (4..4, 0..0));
+        assert_eq!(split_span(10..10, 4), (4..4, 6..6));
+        assert_eq!(split_span(0..4, 4), (0..4, 0..0));
+    }
+}
@@ -1,6 +1,11 @@
-use std::ops::{self, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
-
-use crate::overlap::IndexOverlap;
+use std::ops::{
+    self, Bound, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo,
+    RangeToInclusive,
+};
+
+use crate::error::GetManyError;
+use crate::overlap::{spans_overlap, IndexOverlap, IndexSpan, Span};
+use crate::slice_index::RawManyIndices;
 use crate::SliceManyIndices;
 
 /* -------------------------------------------------------------------------- */
@@ -20,6 +25,10 @@ impl<I> DisjointIndices<I> {
     pub fn get(self) -> I {
         self.0
     }
+
+    pub fn get_ref(&self) -> &I {
+        &self.0
+    }
 }
 
 impl<I: ManyIndices> DisjointIndices<I> {
@@ -33,6 +42,120 @@ impl<I: ManyIndices> DisjointIndices<I> {
     }
 }
 
+impl<const N: usize> DisjointIndices<[usize; N]> {
+    /// Creates a set of disjoint indices, checking it at compile time when evaluated in a const context.
+    ///
+    /// See [`disjoint_indices!`] for a convenient way to do so.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the indices are not disjoint.
+    pub const fn new_const(indices: [usize; N]) -> Self {
+        let mut i = 0;
+        while i < N {
+            let mut j = 0;
+            while j < i {
+                if indices[i] == indices[j] {
+                    panic!("the indices are not disjoint");
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+
+        Self(indices)
+    }
+}
+
+impl<const N: usize> DisjointIndices<[Range<usize>; N]> {
+    /// Creates a set of disjoint ranges, checking it at compile time when evaluated in a const context.
+    ///
+    /// See [`disjoint_indices!`] for a convenient way to do so.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ranges are not disjoint.
+    pub const fn new_const_ranges(indices: [Range<usize>; N]) -> Self {
+        let mut i = 0;
+        while i < N {
+            let mut j = 0;
+            while j < i {
+                if spans_overlap(
+                    &Span::from_range(&indices[i]),
+                    &Span::from_range(&indices[j]),
+                ) {
+                    panic!("the ranges are not disjoint");
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+
+        Self(indices)
+    }
+}
+
+/// Creates a [`DisjointIndices`] from indices or ranges, whose disjointness is checked at compile time.
+///
+/// Each index is either an expression `i`, or a range `a..b` or `a..=b` whose bounds are literals,
+/// constants or parenthesized expressions. Both kinds of ranges may be mixed, but not indices and ranges.
+///
+/// ```
+/// # use slice_get_many_poc::{disjoint_indices, SliceExt};
+/// let mut array = [0, 1, 2, 3, 4, 5, 6, 7];
+///
+/// let [a, b, c] = array.get_many_mut_poc(disjoint_indices![0, 3, 7]).unwrap();
+/// let [d, e] = array.get_many_mut_poc(disjoint_indices![0..2, 4..8]).unwrap();
+///
+/// const MID: usize = 4;
+/// let [f, g] = array.get_many_mut_poc(disjoint_indices![0..=(MID - 1), MID..8]).unwrap();
+/// ```
+///
+/// Overlapping indices fail to compile:
+///
+/// ```compile_fail
+/// # use slice_get_many_poc::disjoint_indices;
+/// let indices = disjoint_indices![0, 3, 0];
+/// ```
+///
+/// So do indices mixed with ranges:
+///
+/// ```compile_fail
+/// # use slice_get_many_poc::disjoint_indices;
+/// let indices = disjoint_indices![0, 3..5];
+/// ```
+#[macro_export]
+macro_rules! disjoint_indices {
+    ( $( $start:tt $(.. $end:tt)? $(..= $end_incl:tt)? ),+ $(,)? ) => {
+        $crate::disjoint_indices!(@ranges [] $( $start $(.. $end)? $(..= $end_incl)? ,)+)
+    };
+
+    ( $( $index:expr ),* $(,)? ) => {
+        const { $crate::DisjointIndices::new_const([ $( $index ),* ]) }
+    };
+
+    // NOTE: the ranges are collected one by one, so that an index among them can be reported.
+    (@ranges [ $($range:expr,)* ] $start:tt .. $end:tt, $($rest:tt)* ) => {
+        $crate::disjoint_indices!(@ranges [ $($range,)* $start..$end, ] $($rest)*)
+    };
+
+    (@ranges [ $($range:expr,)* ] $start:tt ..= $end:tt, $($rest:tt)* ) => {
+        $crate::disjoint_indices!(@ranges [ $($range,)* $start..$end + 1, ] $($rest)*)
+    };
+
+    (@ranges [ $($range:expr,)+ ] ) => {
+        const { $crate::DisjointIndices::new_const_ranges([ $($range),+ ]) }
+    };
+
+    (@ranges [] $($index:tt,)+ ) => {
+        const { $crate::DisjointIndices::new_const([ $($index),+ ]) }
+    };
+
+    (@ranges [ $($range:expr,)* ] $($rest:tt)+ ) => {
+        compile_error!("`disjoint_indices!` cannot mix indices and ranges")
+    };
+}
+
 /* -------------------------------------------------------------------------- */
 
 /// Represents multiple indices for a slice that may borrow the same item multiple times.
@@ -42,6 +165,9 @@ impl<I: ManyIndices> DisjointIndices<I> {
 /// - `is_disjoint` must returns `true` only if the indices are disjoint (i.e. a single item may not be borrowed twice).
 /// - `is_in_bounds` must returns `true` only if all the indices are in bounds.
 /// - `is_disjoint_and_in_bounds` must be equivalent to `is_disjoint() && is_in_bounds()`.
+/// - `check_in_bounds` must returns `Ok` if and only if `is_in_bounds` returns `true`.
+/// - `check_disjoint_and_in_bounds` must returns `Ok` if and only if `is_disjoint_and_in_bounds` returns `true`.
+/// - `check_char_boundaries` must returns `Ok` only if all the indices start and end on a char boundary.
 pub unsafe trait ManyIndices {
     /// Returns `true` if all indices are disjoint (i.e. a single item may not be borrowed twice).
     fn is_disjoint(&self) -> bool;
@@ -56,6 +182,58 @@ pub unsafe trait ManyIndices {
     fn is_disjoint_and_in_bounds(&self, bounds: ops::RangeTo<usize>) -> bool {
         self.is_disjoint() && self.is_in_bounds(bounds)
     }
+
+    /// Checks that the indices are in bounds, reporting which index is at fault otherwise.
+    ///
+    /// The default implementation is built from `is_in_bounds()`,
+    /// so it cannot tell which index is at fault and reports it at position `0`.
+    fn check_in_bounds(&self, bounds: ops::RangeTo<usize>) -> Result<(), GetManyError> {
+        if self.is_in_bounds(bounds) {
+            Ok(())
+        } else {
+            Err(GetManyError::out_of_bounds(0, bounds.end))
+        }
+    }
+
+    /// Checks that the indices are both disjoint and in bounds,
+    /// reporting which indices are at fault otherwise.
+    ///
+    /// Indices that are out of bounds are reported before indices that overlap.
+    ///
+    /// The default implementation is built from `check_in_bounds()` and `is_disjoint()`,
+    /// so it cannot tell which indices overlap and reports an [`Overlap`] at positions `0` and `0`.
+    ///
+    /// [`Overlap`]: crate::GetManyErrorKind::Overlap
+    fn check_disjoint_and_in_bounds(
+        &self,
+        bounds: ops::RangeTo<usize>,
+    ) -> Result<(), GetManyError> {
+        self.check_in_bounds(bounds)?;
+
+        if !self.is_disjoint() {
+            Err(GetManyError::overlap(0, 0, bounds.end))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Checks that the indices start and end on UTF-8 char boundaries of `s`,
+    /// reporting which indices are at fault otherwise.
+    ///
+    /// Indices that are out of the bounds of `s` are reported as not being on a char boundary.
+    ///
+    /// The default implementation rejects any index, since it cannot know where they start and end:
+    /// it must be re-implemented for the indices to be used on a `str`.
+    fn check_char_boundaries(&self, s: &str) -> Result<(), GetManyError> {
+        Err(GetManyError::not_char_boundary(0, s.len()))
+    }
+
+    /// Formats the indices like `Debug` does, so that the errors reporting them can show their value.
+    ///
+    /// The default implementation returns `None`, in which case the errors only show their position.
+    fn fmt_index(&self) -> Option<String> {
+        None
+    }
 }
 
 unsafe impl<I> ManyIndices for DisjointIndices<I>
@@ -70,6 +248,25 @@ where
     fn is_in_bounds(&self, bounds: ops::RangeTo<usize>) -> bool {
         self.0.is_in_bounds(bounds)
     }
+
+    fn check_in_bounds(&self, bounds: ops::RangeTo<usize>) -> Result<(), GetManyError> {
+        self.0.check_in_bounds(bounds)
+    }
+
+    fn check_disjoint_and_in_bounds(
+        &self,
+        bounds: ops::RangeTo<usize>,
+    ) -> Result<(), GetManyError> {
+        self.0.check_in_bounds(bounds)
+    }
+
+    fn check_char_boundaries(&self, s: &str) -> Result<(), GetManyError> {
+        self.0.check_char_boundaries(s)
+    }
+
+    fn fmt_index(&self) -> Option<String> {
+        self.0.fmt_index()
+    }
 }
 
 unsafe impl ManyIndices for usize {
@@ -80,6 +277,23 @@ unsafe impl ManyIndices for usize {
     fn is_in_bounds(&self, bounds: ops::RangeTo<usize>) -> bool {
         bounds.contains(self)
     }
+
+    fn check_in_bounds(&self, bounds: ops::RangeTo<usize>) -> Result<(), GetManyError> {
+        if self.is_in_bounds(bounds) {
+            Ok(())
+        } else {
+            Err(GetManyError::out_of_bounds(0, bounds.end).with_index(self.fmt_index()))
+        }
+    }
+
+    fn check_char_boundaries(&self, _s: &str) -> Result<(), GetManyError> {
+        // NOTE: a single item index cannot be used to index a `str`.
+        Ok(())
+    }
+
+    fn fmt_index(&self) -> Option<String> {
+        Some(format!("{self:?}"))
+    }
 }
 
 unsafe impl ManyIndices for Range<usize> {
@@ -88,8 +302,43 @@ unsafe impl ManyIndices for Range<usize> {
     }
 
     fn is_in_bounds(&self, bounds: ops::RangeTo<usize>) -> bool {
-        self.start < bounds.end && self.end <= bounds.end
+        check_range(Some(self.clone()), bounds).is_ok()
     }
+
+    fn check_in_bounds(&self, bounds: ops::RangeTo<usize>) -> Result<(), GetManyError> {
+        check_range(Some(self.clone()), bounds).map_err(|err| err.with_index(self.fmt_index()))
+    }
+
+    fn check_char_boundaries(&self, s: &str) -> Result<(), GetManyError> {
+        if s.is_char_boundary(self.start) && s.is_char_boundary(self.end) {
+            Ok(())
+        } else {
+            Err(GetManyError::not_char_boundary(0, s.len()).with_index(self.fmt_index()))
+        }
+    }
+
+    fn fmt_index(&self) -> Option<String> {
+        Some(format!("{self:?}"))
+    }
+}
+
+/// Returns `true` if `range` has been exhausted by iterating over it,
+/// in which case it indexes the empty range right after its end.
+pub(crate) fn is_exhausted(range: &RangeInclusive<usize>) -> bool {
+    range.is_empty() && range.start() <= range.end()
+}
+
+/// Resolves an inclusive range into the half-open range it indexes, as std does,
+/// returning `None` if its end cannot be made exclusive.
+pub(crate) fn resolve_inclusive(range: &RangeInclusive<usize>) -> Option<Range<usize>> {
+    let end = range.end().checked_add(1)?;
+    let start = if is_exhausted(range) {
+        end
+    } else {
+        *range.start()
+    };
+
+    Some(start..end)
 }
 
 unsafe impl ManyIndices for RangeInclusive<usize> {
@@ -98,7 +347,24 @@ unsafe impl ManyIndices for RangeInclusive<usize> {
     }
 
     fn is_in_bounds(&self, bounds: ops::RangeTo<usize>) -> bool {
-        *self.start() < bounds.end && *self.end() < bounds.end
+        check_range(resolve_inclusive(self), bounds).is_ok()
+    }
+
+    fn check_in_bounds(&self, bounds: ops::RangeTo<usize>) -> Result<(), GetManyError> {
+        check_range(resolve_inclusive(self), bounds).map_err(|err| err.with_index(self.fmt_index()))
+    }
+
+    fn check_char_boundaries(&self, s: &str) -> Result<(), GetManyError> {
+        match resolve_inclusive(self) {
+            Some(range) if s.is_char_boundary(range.start) && s.is_char_boundary(range.end) => {
+                Ok(())
+            }
+            _ => Err(GetManyError::not_char_boundary(0, s.len()).with_index(self.fmt_index())),
+        }
+    }
+
+    fn fmt_index(&self) -> Option<String> {
+        Some(format!("{self:?}"))
     }
 }
 
@@ -110,6 +376,26 @@ unsafe impl ManyIndices for RangeTo<usize> {
     fn is_in_bounds(&self, bounds: ops::RangeTo<usize>) -> bool {
         self.end <= bounds.end
     }
+
+    fn check_in_bounds(&self, bounds: ops::RangeTo<usize>) -> Result<(), GetManyError> {
+        if self.is_in_bounds(bounds) {
+            Ok(())
+        } else {
+            Err(GetManyError::out_of_bounds(0, bounds.end).with_index(self.fmt_index()))
+        }
+    }
+
+    fn check_char_boundaries(&self, s: &str) -> Result<(), GetManyError> {
+        if s.is_char_boundary(self.end) {
+            Ok(())
+        } else {
+            Err(GetManyError::not_char_boundary(0, s.len()).with_index(self.fmt_index()))
+        }
+    }
+
+    fn fmt_index(&self) -> Option<String> {
+        Some(format!("{self:?}"))
+    }
 }
 
 unsafe impl ManyIndices for RangeToInclusive<usize> {
@@ -120,6 +406,26 @@ unsafe impl ManyIndices for RangeToInclusive<usize> {
     fn is_in_bounds(&self, bounds: ops::RangeTo<usize>) -> bool {
         self.end < bounds.end
     }
+
+    fn check_in_bounds(&self, bounds: ops::RangeTo<usize>) -> Result<(), GetManyError> {
+        if self.is_in_bounds(bounds) {
+            Ok(())
+        } else {
+            Err(GetManyError::out_of_bounds(0, bounds.end).with_index(self.fmt_index()))
+        }
+    }
+
+    fn check_char_boundaries(&self, s: &str) -> Result<(), GetManyError> {
+        if s.is_char_boundary(self.end.saturating_add(1)) {
+            Ok(())
+        } else {
+            Err(GetManyError::not_char_boundary(0, s.len()).with_index(self.fmt_index()))
+        }
+    }
+
+    fn fmt_index(&self) -> Option<String> {
+        Some(format!("{self:?}"))
+    }
 }
 
 unsafe impl ManyIndices for RangeFrom<usize> {
@@ -128,7 +434,95 @@ unsafe impl ManyIndices for RangeFrom<usize> {
     }
 
     fn is_in_bounds(&self, bounds: ops::RangeTo<usize>) -> bool {
-        self.start < bounds.end
+        self.start <= bounds.end
+    }
+
+    fn check_in_bounds(&self, bounds: ops::RangeTo<usize>) -> Result<(), GetManyError> {
+        if self.is_in_bounds(bounds) {
+            Ok(())
+        } else {
+            Err(GetManyError::out_of_bounds(0, bounds.end).with_index(self.fmt_index()))
+        }
+    }
+
+    fn check_char_boundaries(&self, s: &str) -> Result<(), GetManyError> {
+        if s.is_char_boundary(self.start) {
+            Ok(())
+        } else {
+            Err(GetManyError::not_char_boundary(0, s.len()).with_index(self.fmt_index()))
+        }
+    }
+
+    fn fmt_index(&self) -> Option<String> {
+        Some(format!("{self:?}"))
+    }
+}
+
+/// Resolves the bounds of `range` into its included start and its excluded end,
+/// the end being `None` if it is unbounded, or returns `None` if a bound overflows.
+pub(crate) fn resolve_bounds<R: RangeBounds<usize>>(range: &R) -> Option<(usize, Option<usize>)> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1)?,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => Some(end.checked_add(1)?),
+        Bound::Excluded(&end) => Some(end),
+        Bound::Unbounded => None,
+    };
+
+    Some((start, end))
+}
+
+/// Resolves a pair of bounds into a range for a slice of length `len`,
+/// returning `None` if a bound overflows.
+fn resolve_bound_pair(range: &(Bound<usize>, Bound<usize>), len: usize) -> Option<Range<usize>> {
+    let (start, end) = resolve_bounds(range)?;
+
+    Some(start..end.unwrap_or(len))
+}
+
+/// Checks a resolved range against `bounds` as std does,
+/// `None` standing for a range whose bounds overflow.
+///
+/// A range that starts after its end is invalid, even if it is in bounds.
+fn check_range(
+    range: Option<Range<usize>>,
+    bounds: ops::RangeTo<usize>,
+) -> Result<(), GetManyError> {
+    match range {
+        Some(range) if range.start > range.end => Err(GetManyError::invalid_range(0, bounds.end)),
+        Some(range) if range.end <= bounds.end => Ok(()),
+        _ => Err(GetManyError::out_of_bounds(0, bounds.end)),
+    }
+}
+
+unsafe impl ManyIndices for (Bound<usize>, Bound<usize>) {
+    fn is_disjoint(&self) -> bool {
+        true
+    }
+
+    fn is_in_bounds(&self, bounds: ops::RangeTo<usize>) -> bool {
+        check_range(resolve_bound_pair(self, bounds.end), bounds).is_ok()
+    }
+
+    fn check_in_bounds(&self, bounds: ops::RangeTo<usize>) -> Result<(), GetManyError> {
+        check_range(resolve_bound_pair(self, bounds.end), bounds)
+            .map_err(|err| err.with_index(self.fmt_index()))
+    }
+
+    fn check_char_boundaries(&self, s: &str) -> Result<(), GetManyError> {
+        match resolve_bound_pair(self, s.len()) {
+            Some(range) if s.is_char_boundary(range.start) && s.is_char_boundary(range.end) => {
+                Ok(())
+            }
+            _ => Err(GetManyError::not_char_boundary(0, s.len()).with_index(self.fmt_index())),
+        }
+    }
+
+    fn fmt_index(&self) -> Option<String> {
+        Some(format!("{self:?}"))
     }
 }
 
@@ -140,6 +534,18 @@ unsafe impl ManyIndices for RangeFull {
     fn is_in_bounds(&self, _bounds: ops::RangeTo<usize>) -> bool {
         true
     }
+
+    fn check_in_bounds(&self, _bounds: ops::RangeTo<usize>) -> Result<(), GetManyError> {
+        Ok(())
+    }
+
+    fn check_char_boundaries(&self, _s: &str) -> Result<(), GetManyError> {
+        Ok(())
+    }
+
+    fn fmt_index(&self) -> Option<String> {
+        Some(format!("{self:?}"))
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -150,6 +556,10 @@ where
 {
     fn is_disjoint(&self) -> bool {
         for (i, idx) in self.iter().enumerate() {
+            if !idx.is_disjoint() {
+                return false;
+            }
+
             for idx2 in &self[..i] {
                 if idx.overlap(idx2) {
                     return false;
@@ -166,7 +576,7 @@ where
 
     fn is_disjoint_and_in_bounds(&self, bounds: ops::RangeTo<usize>) -> bool {
         for (i, idx) in self.iter().enumerate() {
-            if !idx.is_in_bounds(bounds) {
+            if !idx.is_disjoint_and_in_bounds(bounds) {
                 return false;
             }
 
@@ -179,6 +589,230 @@ where
 
         true
     }
+
+    fn check_in_bounds(&self, bounds: ops::RangeTo<usize>) -> Result<(), GetManyError> {
+        for (i, idx) in self.iter().enumerate() {
+            idx.check_in_bounds(bounds).map_err(|err| err.at(i))?;
+        }
+
+        Ok(())
+    }
+
+    fn check_disjoint_and_in_bounds(
+        &self,
+        bounds: ops::RangeTo<usize>,
+    ) -> Result<(), GetManyError> {
+        self.check_in_bounds(bounds)?;
+
+        for (i, idx) in self.iter().enumerate() {
+            idx.check_disjoint_and_in_bounds(bounds)
+                .map_err(|err| err.at(i))?;
+
+            for (j, idx2) in self[..i].iter().enumerate() {
+                if idx.overlap(idx2) {
+                    return Err(GetManyError::overlap(j, i, bounds.end)
+                        .with_index(idx2.fmt_index())
+                        .with_index(idx.fmt_index()));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_char_boundaries(&self, s: &str) -> Result<(), GetManyError> {
+        for (i, idx) in self.iter().enumerate() {
+            idx.check_char_boundaries(s).map_err(|err| err.at(i))?;
+        }
+
+        Ok(())
+    }
+
+    fn fmt_index(&self) -> Option<String> {
+        fmt_list(self)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Formats a list of indices like `Debug` does, if each of them can be formatted.
+fn fmt_list<I: ManyIndices>(indices: &[I]) -> Option<String> {
+    let indices: Option<Vec<String>> = indices.iter().map(ManyIndices::fmt_index).collect();
+
+    Some(format!("[{}]", indices?.join(", ")))
+}
+
+/// Returns the positions of two overlapping indices, if any.
+///
+/// The indices are sorted by the start of their span so that only `O(n log n)` comparisons are needed.
+pub(crate) fn find_overlap<I>(indices: &[I]) -> Option<(usize, usize)>
+where
+    I: IndexOverlap<I> + IndexSpan,
+{
+    let mut order: Vec<usize> = (0..indices.len()).collect();
+    order.sort_unstable_by_key(|&i| indices[i].span().start);
+
+    find_overlap_in_order(indices, order)
+}
+
+/// Returns the positions of two overlapping indices, if any.
+///
+/// `order` must yield the positions of `indices` by ascending start of their span.
+/// Each index is then only compared against the previous index that reaches the furthest:
+/// if it does not overlap this one, it cannot overlap any of the previous indices.
+pub(crate) fn find_overlap_in_order<I>(
+    indices: &[I],
+    order: impl IntoIterator<Item = usize>,
+) -> Option<(usize, usize)>
+where
+    I: IndexOverlap<I> + IndexSpan,
+{
+    let mut furthest: Option<(usize, Span)> = None;
+
+    for i in order {
+        let span = indices[i].span();
+
+        match furthest {
+            Some((j, _)) if indices[i].overlap(&indices[j]) => return Some((j, i)),
+            Some((_, furthest_span)) if !span.ends_after(&furthest_span) => {}
+            _ => furthest = Some((i, span)),
+        }
+    }
+
+    None
+}
+
+unsafe impl<I> ManyIndices for [I]
+where
+    I: ManyIndices + IndexOverlap<I> + IndexSpan,
+{
+    fn is_disjoint(&self) -> bool {
+        self.iter().all(ManyIndices::is_disjoint) && find_overlap(self).is_none()
+    }
+
+    fn is_in_bounds(&self, bounds: ops::RangeTo<usize>) -> bool {
+        self.iter().all(|i| i.is_in_bounds(bounds))
+    }
+
+    fn is_disjoint_and_in_bounds(&self, bounds: ops::RangeTo<usize>) -> bool {
+        // NOTE: checking the bounds first is cheaper.
+        self.is_in_bounds(bounds) && self.is_disjoint()
+    }
+
+    fn check_in_bounds(&self, bounds: ops::RangeTo<usize>) -> Result<(), GetManyError> {
+        for (i, idx) in self.iter().enumerate() {
+            idx.check_in_bounds(bounds).map_err(|err| err.at(i))?;
+        }
+
+        Ok(())
+    }
+
+    fn check_disjoint_and_in_bounds(
+        &self,
+        bounds: ops::RangeTo<usize>,
+    ) -> Result<(), GetManyError> {
+        self.check_in_bounds(bounds)?;
+
+        for (i, idx) in self.iter().enumerate() {
+            idx.check_disjoint_and_in_bounds(bounds)
+                .map_err(|err| err.at(i))?;
+        }
+
+        match find_overlap(self) {
+            Some((i, j)) => {
+                let (i, j) = (i.min(j), i.max(j));
+
+                Err(GetManyError::overlap(i, j, bounds.end)
+                    .with_index(self[i].fmt_index())
+                    .with_index(self[j].fmt_index()))
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn check_char_boundaries(&self, s: &str) -> Result<(), GetManyError> {
+        for (i, idx) in self.iter().enumerate() {
+            idx.check_char_boundaries(s).map_err(|err| err.at(i))?;
+        }
+
+        Ok(())
+    }
+
+    fn fmt_index(&self) -> Option<String> {
+        fmt_list(self)
+    }
+}
+
+unsafe impl<I> ManyIndices for &[I]
+where
+    I: ManyIndices + IndexOverlap<I> + IndexSpan,
+{
+    fn is_disjoint(&self) -> bool {
+        (**self).is_disjoint()
+    }
+
+    fn is_in_bounds(&self, bounds: ops::RangeTo<usize>) -> bool {
+        (**self).is_in_bounds(bounds)
+    }
+
+    fn is_disjoint_and_in_bounds(&self, bounds: ops::RangeTo<usize>) -> bool {
+        (**self).is_disjoint_and_in_bounds(bounds)
+    }
+
+    fn check_in_bounds(&self, bounds: ops::RangeTo<usize>) -> Result<(), GetManyError> {
+        (**self).check_in_bounds(bounds)
+    }
+
+    fn check_disjoint_and_in_bounds(
+        &self,
+        bounds: ops::RangeTo<usize>,
+    ) -> Result<(), GetManyError> {
+        (**self).check_disjoint_and_in_bounds(bounds)
+    }
+
+    fn check_char_boundaries(&self, s: &str) -> Result<(), GetManyError> {
+        (**self).check_char_boundaries(s)
+    }
+
+    fn fmt_index(&self) -> Option<String> {
+        (**self).fmt_index()
+    }
+}
+
+unsafe impl<I> ManyIndices for Vec<I>
+where
+    I: ManyIndices + IndexOverlap<I> + IndexSpan,
+{
+    fn is_disjoint(&self) -> bool {
+        self.as_slice().is_disjoint()
+    }
+
+    fn is_in_bounds(&self, bounds: ops::RangeTo<usize>) -> bool {
+        self.as_slice().is_in_bounds(bounds)
+    }
+
+    fn is_disjoint_and_in_bounds(&self, bounds: ops::RangeTo<usize>) -> bool {
+        self.as_slice().is_disjoint_and_in_bounds(bounds)
+    }
+
+    fn check_in_bounds(&self, bounds: ops::RangeTo<usize>) -> Result<(), GetManyError> {
+        self.as_slice().check_in_bounds(bounds)
+    }
+
+    fn check_disjoint_and_in_bounds(
+        &self,
+        bounds: ops::RangeTo<usize>,
+    ) -> Result<(), GetManyError> {
+        self.as_slice().check_disjoint_and_in_bounds(bounds)
+    }
+
+    fn check_char_boundaries(&self, s: &str) -> Result<(), GetManyError> {
+        self.as_slice().check_char_boundaries(s)
+    }
+
+    fn fmt_index(&self) -> Option<String> {
+        self.as_slice().fmt_index()
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -195,22 +829,23 @@ where
     }
 }
 
+impl<'slice, Slice: ?Sized, I> RawManyIndices<'slice, Slice> for DisjointIndices<I>
+where
+    I: RawManyIndices<'slice, Slice>,
+{
+    unsafe fn get_many_unchecked_raw(self, slice: *mut Slice) -> Self::Output {
+        // SAFETY: contract uphold by the caller.
+        unsafe { RawManyIndices::get_many_unchecked_raw(self.0, slice) }
+    }
+}
+
 /* -------------------------------------------------------------------------- */
 
 macro_rules! impl_tuple {
-
     ( $($I:ident)+ ) => {
-        impl_tuple!(@recursive $($I)*);
-    };
-
-    (@recursive $first:ident $($next:ident)* ) => {
-        impl_tuple!(@prepare [] $first $($next)*);
-        impl_tuple!(@recursive $($next)*);
+        impl_tuple!(@prepare [] $($I)*);
     };
 
-    (@recursive ) => { /* stop condition */ };
-
-
     (@prepare [$($prepared:tt)*] $current:ident $($next:ident)*  ) => {
         impl_tuple!{
             @prepare
@@ -253,20 +888,145 @@ macro_rules! impl_tuple {
                     && ManyIndices::is_in_bounds($I, bounds)
                 )*
             }
+
+            #[allow(unused_assignments)]
+            fn check_in_bounds(&self, bounds: ops::RangeTo<usize>) -> Result<(), GetManyError> {
+                let ( $($I,)* ) = self;
+
+                let mut position = 0;
+                $(
+                    ManyIndices::check_in_bounds($I, bounds)
+                        .map_err(|err| err.at(position))?;
+                    position += 1;
+                )*
+
+                Ok(())
+            }
+
+            #[allow(unused_mut, unused_variables, unused_assignments)]
+            fn check_disjoint_and_in_bounds(
+                &self,
+                bounds: ops::RangeTo<usize>,
+            ) -> Result<(), GetManyError> {
+                self.check_in_bounds(bounds)?;
+
+                let ( $($I,)* ) = self;
+
+                let mut position = 0;
+                $(
+                    ManyIndices::check_disjoint_and_in_bounds($I, bounds)
+                        .map_err(|err| err.at(position))?;
+
+                    let mut next_position = position;
+                    $(
+                        next_position += 1;
+                        if IndexOverlap::overlap($I, $INext) {
+                            return Err(GetManyError::overlap(position, next_position, bounds.end)
+                                .with_index($I.fmt_index())
+                                .with_index($INext.fmt_index()));
+                        }
+                    )*
+                    position += 1;
+                )*
+
+                Ok(())
+            }
+
+            #[allow(unused_assignments)]
+            fn check_char_boundaries(&self, s: &str) -> Result<(), GetManyError> {
+                let ( $($I,)* ) = self;
+
+                let mut position = 0;
+                $(
+                    ManyIndices::check_char_boundaries($I, s)
+                        .map_err(|err| err.at(position))?;
+                    position += 1;
+                )*
+
+                Ok(())
+            }
+
+            fn fmt_index(&self) -> Option<String> {
+                let ( $($I,)* ) = self;
+                let indices = [ $( ManyIndices::fmt_index($I)?, )* ];
+
+                // NOTE: like `Debug`, a trailing comma tells a 1-tuple apart.
+                let comma = if indices.len() == 1 { "," } else { "" };
+                Some(format!("({}{comma})", indices.join(", ")))
+            }
         }
     };
 }
 
-impl_tuple!(I0 I1 I2 I3 I4 I5 I6 I7 I8 I9 I10 I11);
+for_each_tuple!(impl_tuple);
 
 /* -------------------------------------------------------------------------- */
 
 #[cfg(test)]
 mod tests {
-    use crate::DisjointIndices;
+    use std::fmt::Debug;
+    use std::ops::Bound;
+    use std::slice::SliceIndex;
+
+    use crate::{DisjointIndices, GetManyError, GetManyErrorKind, Mut, Shared};
 
     use super::ManyIndices;
 
+    /// Asserts that `index` is in bounds exactly when std's `SliceIndex` accepts it.
+    #[track_caller]
+    fn assert_bounds_like_std<I>(index: I)
+    where
+        I: ManyIndices + SliceIndex<[u8]> + Clone + Debug,
+    {
+        let items = [0u8; 4];
+
+        for len in 0..=items.len() {
+            let expected = items[..len].get(index.clone()).is_some();
+
+            assert_eq!(
+                index.is_in_bounds(..len),
+                expected,
+                "{index:?} for length {len}"
+            );
+            assert_eq!(
+                index.check_disjoint_and_in_bounds(..len).is_ok(),
+                expected,
+                "{index:?} for length {len}"
+            );
+        }
+    }
+
+    #[test]
+    fn bounds_like_std() {
+        let points = [0, 1, 2, 3, 4, 5, usize::MAX - 1, usize::MAX];
+
+        assert_bounds_like_std(..);
+
+        for a in points {
+            assert_bounds_like_std(a);
+            assert_bounds_like_std(a..);
+            assert_bounds_like_std(..a);
+            assert_bounds_like_std(..=a);
+
+            for b in points {
+                assert_bounds_like_std(a..b);
+                assert_bounds_like_std(a..=b);
+
+                if a <= b && b <= 5 {
+                    let mut exhausted = a..=b;
+                    exhausted.by_ref().for_each(drop);
+                    assert_bounds_like_std(exhausted);
+                }
+
+                for start in [Bound::Included(a), Bound::Excluded(a), Bound::Unbounded] {
+                    for end in [Bound::Included(b), Bound::Excluded(b), Bound::Unbounded] {
+                        assert_bounds_like_std((start, end));
+                    }
+                }
+            }
+        }
+    }
+
     #[test]
     fn disjoint_indices() {
         assert!(DisjointIndices::new([0, 5, 9]).is_some());
@@ -283,6 +1043,21 @@ mod tests {
         assert!(DisjointIndices::new((10.., 2..10)).is_some());
     }
 
+    #[test]
+    fn const_disjoint_indices() {
+        const INDICES: DisjointIndices<[usize; 3]> = DisjointIndices::new_const([0, 5, 9]);
+        assert_eq!(INDICES.get(), [0, 5, 9]);
+
+        let ranges = disjoint_indices![0..4, 4..6, 5..5];
+        assert_eq!(ranges.get(), [0..4, 4..6, 5..5]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn const_disjoint_indices_overlap() {
+        DisjointIndices::new_const_ranges([0..4, 2..6]);
+    }
+
     #[test]
     fn array_index() {
         let array = [2, 7, 9];
@@ -370,6 +1145,180 @@ mod tests {
         assert!(!tuple.is_disjoint_and_in_bounds(..12));
         assert!(!tuple.is_disjoint_and_in_bounds(..13));
     }
+
+    #[test]
+    fn dynamic() {
+        let indices = vec![9, 2, 7];
+
+        assert!(indices.is_disjoint());
+        assert!(!indices.is_in_bounds(..9));
+        assert!(indices.is_disjoint_and_in_bounds(..10));
+        assert!(indices.as_slice().is_disjoint_and_in_bounds(..10));
+    }
+
+    #[test]
+    fn unbounded_overlap() {
+        assert!(DisjointIndices::new([usize::MAX, usize::MAX]).is_none());
+        assert!(DisjointIndices::new([usize::MAX.., usize::MAX..]).is_none());
+        assert!(DisjointIndices::new((usize::MAX, usize::MAX..)).is_none());
+        assert!(DisjointIndices::new(vec![usize::MAX, 3, usize::MAX]).is_none());
+        assert!(DisjointIndices::new(vec![usize::MAX.., usize::MAX..]).is_none());
+        assert!(DisjointIndices::new([usize::MAX - 2, usize::MAX - 1]).is_some());
+        assert!(DisjointIndices::new([usize::MAX - 1, usize::MAX]).is_some());
+        assert!(DisjointIndices::new((0..usize::MAX, usize::MAX..)).is_some());
+    }
+
+    #[test]
+    fn dynamic_overlap() {
+        assert!(!vec![9, 2, 7, 2].is_disjoint());
+        assert!(!vec![8..12, 0..5, 5..8, 3..4].is_disjoint());
+        assert!(!vec![0..10, 12..14, 1..2].is_disjoint());
+        assert!(!vec![..0, ..3, ..0, ..5].is_disjoint());
+        assert!(vec![..0, ..3, ..0].is_disjoint());
+        assert!(!vec![20..=30, 3..=5, 25..=25].is_disjoint());
+
+        assert_eq!(
+            vec![8..12, 0..5, 5..8, 3..4].check_disjoint_and_in_bounds(..12),
+            Err(GetManyError::overlap(1, 3, 12)
+                .with_index(index("0..5"))
+                .with_index(index("3..4")))
+        );
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn array_report() {
+        assert_eq!([2, 7, 9].check_disjoint_and_in_bounds(..10), Ok(()));
+        assert_eq!(
+            [2, 7, 9].check_disjoint_and_in_bounds(..9),
+            Err(GetManyError::out_of_bounds(2, 9).with_index(index("9")))
+        );
+        assert_eq!(
+            [2, 7, 2].check_disjoint_and_in_bounds(..10),
+            Err(GetManyError::overlap(0, 2, 10)
+                .with_index(index("2"))
+                .with_index(index("2")))
+        );
+        assert_eq!(
+            [2..5, 7..6].check_disjoint_and_in_bounds(..10),
+            Err(GetManyError::invalid_range(1, 10).with_index(index("7..6")))
+        );
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn tuple_report() {
+        assert_eq!((2, 5, 7..9).check_disjoint_and_in_bounds(..9), Ok(()));
+        assert_eq!(
+            (2, 5, 7..9).check_disjoint_and_in_bounds(..8),
+            Err(GetManyError::out_of_bounds(2, 8).with_index(index("7..9")))
+        );
+        assert_eq!(
+            (2, 5..7, 6).check_disjoint_and_in_bounds(..8),
+            Err(GetManyError::overlap(1, 2, 8)
+                .with_index(index("5..7"))
+                .with_index(index("6")))
+        );
+        assert_eq!(
+            (2, 5..=3).check_disjoint_and_in_bounds(..8),
+            Err(GetManyError::invalid_range(1, 8).with_index(index("5..=3")))
+        );
+    }
+
+    #[test]
+    fn report_order() {
+        // NOTE: out-of-bounds indices are reported before overlapping ones, whatever their positions.
+        let err = Err(GetManyError::out_of_bounds(2, 10).with_index(index("20..21")));
+
+        assert_eq!([0..5, 3..4, 20..21].check_disjoint_and_in_bounds(..10), err);
+        assert_eq!((0..5, 3, 20..21).check_disjoint_and_in_bounds(..10), err);
+        assert_eq!(
+            vec![0..5, 3..4, 20..21].check_disjoint_and_in_bounds(..10),
+            err
+        );
+        assert_eq!(
+            DisjointIndices::new([0..1, 3..4, 20..21])
+                .unwrap()
+                .check_disjoint_and_in_bounds(..10),
+            err
+        );
+    }
+
+    #[test]
+    fn report_values() {
+        let err = (Shared(0..4), Mut(3), [Mut(7), Mut(8)]).check_disjoint_and_in_bounds(..10);
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "indices Shared(0..4) at position 0 and Mut(3) at position 1 overlap"
+        );
+
+        let err = (Some(3), 3).check_disjoint_and_in_bounds(..10);
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "indices Some(3) at position 0 and 3 at position 1 overlap"
+        );
+
+        let err = [[0, 1], [2, 3]].check_disjoint_and_in_bounds(..3);
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "index 3 at position 1 out of range for slice of length 3"
+        );
+
+        let err = ([0, 1], [2, 2])
+            .check_disjoint_and_in_bounds(..3)
+            .unwrap_err();
+        assert_eq!(
+            err.kind(),
+            GetManyErrorKind::Overlap {
+                first: 1,
+                second: 1
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "indices 2 at position 1 and 2 at position 1 overlap"
+        );
+
+        let err = ((1, 2..4), [1]).check_disjoint_and_in_bounds(..5);
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "indices (1, 2..4) at position 0 and [1] at position 1 overlap"
+        );
+    }
+
+    fn index(index: &str) -> Option<String> {
+        Some(index.to_owned())
+    }
+
+    /// A set of indices that only implements the required methods of `ManyIndices`.
+    struct Pair(usize, usize);
+
+    unsafe impl ManyIndices for Pair {
+        fn is_disjoint(&self) -> bool {
+            self.0 != self.1
+        }
+
+        fn is_in_bounds(&self, bounds: std::ops::RangeTo<usize>) -> bool {
+            bounds.contains(&self.0) && bounds.contains(&self.1)
+        }
+    }
+
+    #[test]
+    fn default_report() {
+        assert_eq!(Pair(2, 5).check_disjoint_and_in_bounds(..8), Ok(()));
+        assert_eq!(
+            Pair(2, 9).check_disjoint_and_in_bounds(..8),
+            Err(GetManyError::out_of_bounds(0, 8))
+        );
+        assert_eq!(
+            Pair(2, 2).check_disjoint_and_in_bounds(..8),
+            Err(GetManyError::overlap(0, 0, 8))
+        );
+        assert_eq!(
+            Pair(2, 5).check_char_boundaries("hello"),
+            Err(GetManyError::not_char_boundary(0, 5))
+        );
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -0,0 +1,155 @@
+use crate::error::GetManyError;
+use crate::{DisjointIndices, ManyIndices, SliceSingleIndex};
+
+/* -------------------------------------------------------------------------- */
+
+/// A slice against which a single index can be checked.
+pub trait CheckIndex {
+    /// Checks that `index` is in bounds of the slice, and on UTF-8 char boundaries for `str`.
+    fn check_index<I: ManyIndices + ?Sized>(&self, index: &I) -> Result<(), GetManyError>;
+}
+
+impl<T> CheckIndex for [T] {
+    fn check_index<I: ManyIndices + ?Sized>(&self, index: &I) -> Result<(), GetManyError> {
+        index.check_disjoint_and_in_bounds(..self.len())
+    }
+}
+
+impl CheckIndex for str {
+    fn check_index<I: ManyIndices + ?Sized>(&self, index: &I) -> Result<(), GetManyError> {
+        index.check_disjoint_and_in_bounds(..self.len())?;
+        index.check_char_boundaries(self)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Represents multiple indices for a slice, each of which may be missing from it.
+pub trait SliceEachIndices<'slice, Slice: ?Sized> {
+    type Output;
+
+    /// Returns a mutable reference to each index that is in bounds, or [`None`] for the others,
+    /// without checking that the indices are disjoint.
+    ///
+    /// # Safety
+    ///
+    /// Calling this method with overlapping indices is *undefined behavior*
+    /// even if the resulting references are not used.
+    unsafe fn get_each_unchecked_mut(self, slice: &'slice mut Slice) -> Self::Output;
+}
+
+impl<'slice, Slice, I, const N: usize> SliceEachIndices<'slice, Slice> for [I; N]
+where
+    Slice: CheckIndex + ?Sized,
+    I: ManyIndices + SliceSingleIndex<'slice, Slice>,
+{
+    type Output = [Option<I::Output>; N];
+
+    unsafe fn get_each_unchecked_mut(self, slice: &'slice mut Slice) -> Self::Output {
+        // NOTE: all the indices are checked before handing out any reference into the slice.
+        let indices = self.map(|idx| {
+            let is_valid = slice.check_index(&idx).is_ok();
+            (idx, is_valid)
+        });
+        let slice: *mut Slice = slice;
+
+        indices.map(|(idx, is_valid)| {
+            // SAFETY: the index is in bounds and the caller ensures that the indices are disjoint.
+            is_valid.then(|| unsafe { idx.get_unchecked_raw(slice) })
+        })
+    }
+}
+
+impl<'slice, Slice, I> SliceEachIndices<'slice, Slice> for Vec<I>
+where
+    Slice: CheckIndex + ?Sized,
+    I: ManyIndices + SliceSingleIndex<'slice, Slice>,
+{
+    type Output = Vec<Option<I::Output>>;
+
+    unsafe fn get_each_unchecked_mut(self, slice: &'slice mut Slice) -> Self::Output {
+        let is_valid: Vec<bool> = self
+            .iter()
+            .map(|idx| slice.check_index(idx).is_ok())
+            .collect();
+        let slice: *mut Slice = slice;
+
+        // SAFETY: the indices are in bounds and the caller ensures that they are disjoint.
+        self.into_iter()
+            .zip(is_valid)
+            .map(|(idx, is_valid)| is_valid.then(|| unsafe { idx.get_unchecked_raw(slice) }))
+            .collect()
+    }
+}
+
+impl<'slice, Slice, I> SliceEachIndices<'slice, Slice> for &[I]
+where
+    Slice: CheckIndex + ?Sized,
+    I: ManyIndices + SliceSingleIndex<'slice, Slice> + Clone,
+{
+    type Output = Vec<Option<I::Output>>;
+
+    unsafe fn get_each_unchecked_mut(self, slice: &'slice mut Slice) -> Self::Output {
+        let is_valid: Vec<bool> = self
+            .iter()
+            .map(|idx| slice.check_index(idx).is_ok())
+            .collect();
+        let slice: *mut Slice = slice;
+
+        // SAFETY: the indices are in bounds and the caller ensures that they are disjoint.
+        self.iter()
+            .cloned()
+            .zip(is_valid)
+            .map(|(idx, is_valid)| is_valid.then(|| unsafe { idx.get_unchecked_raw(slice) }))
+            .collect()
+    }
+}
+
+macro_rules! impl_tuple {
+    ( $($I:ident)+ ) => {
+        #[allow(non_snake_case)]
+        impl<'slice, Slice, $($I,)*> SliceEachIndices<'slice, Slice> for ($($I,)*)
+        where
+            Slice: CheckIndex + ?Sized,
+            $( $I: ManyIndices + SliceSingleIndex<'slice, Slice>, )*
+        {
+            type Output = ( $( Option<$I::Output>, )* );
+
+            unsafe fn get_each_unchecked_mut(self, slice: &'slice mut Slice) -> Self::Output {
+                let ( $( $I, )* ) = self;
+
+                // NOTE: all the indices are checked before handing out any reference into the slice.
+                $(
+                    let $I = (slice.check_index(&$I).is_ok(), $I);
+                )*
+
+                let slice: *mut Slice = slice;
+
+                (
+                    $({
+                        let (is_valid, idx) = $I;
+
+                        // SAFETY: the index is in bounds and the caller ensures that the indices are disjoint.
+                        is_valid.then(|| unsafe { idx.get_unchecked_raw(slice) })
+                    },)*
+                )
+            }
+        }
+    };
+}
+
+for_each_tuple!(impl_tuple);
+
+impl<'slice, Slice: ?Sized, I> SliceEachIndices<'slice, Slice> for DisjointIndices<I>
+where
+    I: SliceEachIndices<'slice, Slice>,
+{
+    type Output = I::Output;
+
+    unsafe fn get_each_unchecked_mut(self, slice: &'slice mut Slice) -> Self::Output {
+        // SAFETY: contract uphold by the caller.
+        unsafe { self.get().get_each_unchecked_mut(slice) }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,221 @@
+use std::error::Error;
+use std::fmt;
+
+/* -------------------------------------------------------------------------- */
+
+/// The error returned when a set of indices is rejected for a slice.
+///
+/// Positions refer to the place of the offending index inside the set of indices,
+/// e.g. `1` for `5..9` in `[0, 5..9]`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GetManyError {
+    kind: GetManyErrorKind,
+    len: usize,
+    /// The offending indices formatted with `Debug`, in the order of their positions, if known.
+    indices: Vec<Option<String>>,
+}
+
+/// The reason why a set of indices has been rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum GetManyErrorKind {
+    /// The index at `position` is out of the bounds of the slice.
+    OutOfBounds { position: usize },
+    /// The indices at `first` and `second` may borrow the same item.
+    ///
+    /// `first` is never greater than `second`, and both are the same
+    /// if the overlapping indices are in a single nested group of indices, like `[2, 2]` in `[[0, 1], [2, 2]]`.
+    /// Both are `0` if the set of indices cannot tell which of its indices overlap,
+    /// as with the default implementation of [`ManyIndices::check_disjoint_and_in_bounds`].
+    ///
+    /// [`ManyIndices::check_disjoint_and_in_bounds`]: crate::ManyIndices::check_disjoint_and_in_bounds
+    Overlap { first: usize, second: usize },
+    /// The range at `position` starts after its end.
+    InvalidRange { position: usize },
+    /// The range at `position` does not start or does not end on a UTF-8 char boundary.
+    NotCharBoundary { position: usize },
+}
+
+impl GetManyError {
+    pub(crate) fn out_of_bounds(position: usize, len: usize) -> Self {
+        Self {
+            kind: GetManyErrorKind::OutOfBounds { position },
+            len,
+            indices: Vec::new(),
+        }
+    }
+
+    /// `first` must not be greater than `second`.
+    pub(crate) fn overlap(first: usize, second: usize, len: usize) -> Self {
+        debug_assert!(first <= second);
+
+        Self {
+            kind: GetManyErrorKind::Overlap { first, second },
+            len,
+            indices: Vec::new(),
+        }
+    }
+
+    pub(crate) fn invalid_range(position: usize, len: usize) -> Self {
+        Self {
+            kind: GetManyErrorKind::InvalidRange { position },
+            len,
+            indices: Vec::new(),
+        }
+    }
+
+    pub(crate) fn not_char_boundary(position: usize, len: usize) -> Self {
+        Self {
+            kind: GetManyErrorKind::NotCharBoundary { position },
+            len,
+            indices: Vec::new(),
+        }
+    }
+
+    /// Records the value of an offending index, as returned by [`ManyIndices::fmt_index`],
+    /// after the ones already recorded.
+    ///
+    /// [`ManyIndices::fmt_index`]: crate::ManyIndices::fmt_index
+    pub(crate) fn with_index(mut self, index: Option<String>) -> Self {
+        self.indices.push(index);
+        self
+    }
+
+    /// Reports the error at the `position` of the element of a set of indices that failed.
+    ///
+    /// The positions reported inside of a nested group of indices are replaced by the position of the group.
+    pub(crate) fn at(self, position: usize) -> Self {
+        let kind = match self.kind {
+            GetManyErrorKind::OutOfBounds { .. } => GetManyErrorKind::OutOfBounds { position },
+            GetManyErrorKind::Overlap { .. } => GetManyErrorKind::Overlap {
+                first: position,
+                second: position,
+            },
+            GetManyErrorKind::InvalidRange { .. } => GetManyErrorKind::InvalidRange { position },
+            GetManyErrorKind::NotCharBoundary { .. } => {
+                GetManyErrorKind::NotCharBoundary { position }
+            }
+        };
+
+        Self { kind, ..self }
+    }
+
+    /// Returns the reason why the indices have been rejected.
+    pub fn kind(&self) -> GetManyErrorKind {
+        self.kind
+    }
+
+    /// Returns the length of the slice the indices have been checked against.
+    pub fn slice_len(&self) -> usize {
+        self.len
+    }
+}
+
+impl fmt::Display for GetManyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // NOTE: the values of the indices are only known for the indices that format themselves.
+        let index = |i: usize| {
+            self.indices
+                .get(i)
+                .and_then(Option::as_ref)
+                .map(|index| format!("{index} "))
+                .unwrap_or_default()
+        };
+
+        match self.kind {
+            GetManyErrorKind::OutOfBounds { position } => write!(
+                f,
+                "index {}at position {position} out of range for slice of length {}",
+                index(0),
+                self.len
+            ),
+            GetManyErrorKind::Overlap { first, second } => write!(
+                f,
+                "indices {}at position {first} and {}at position {second} overlap",
+                index(0),
+                index(1)
+            ),
+            GetManyErrorKind::InvalidRange { position } => write!(
+                f,
+                "range {}at position {position} starts after its end",
+                index(0)
+            ),
+            GetManyErrorKind::NotCharBoundary { position } => write!(
+                f,
+                "range {}at position {position} is not on a char boundary of str of length {}",
+                index(0),
+                self.len
+            ),
+        }
+    }
+}
+
+impl Error for GetManyError {}
+
+/* -------------------------------------------------------------------------- */
+
+/// The error returned when parsing a set of indices fails.
+///
+/// Columns start at `1` and count the chars of the parsed string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseIndicesError {
+    kind: ParseIndicesErrorKind,
+    column: usize,
+}
+
+/// The reason why parsing a set of indices failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParseIndicesErrorKind {
+    /// The index is not a number or a range of numbers.
+    InvalidIndex,
+    /// The index overlaps a previous index, while disjoint indices were requested.
+    Overlap,
+}
+
+impl ParseIndicesError {
+    pub(crate) fn invalid_index(column: usize) -> Self {
+        Self {
+            kind: ParseIndicesErrorKind::InvalidIndex,
+            column,
+        }
+    }
+
+    pub(crate) fn overlap(column: usize) -> Self {
+        Self {
+            kind: ParseIndicesErrorKind::Overlap,
+            column,
+        }
+    }
+
+    /// Returns the reason why parsing failed.
+    pub fn kind(&self) -> ParseIndicesErrorKind {
+        self.kind
+    }
+
+    /// Returns the column of the offending index.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+}
+
+impl fmt::Display for ParseIndicesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ParseIndicesErrorKind::InvalidIndex => {
+                write!(f, "invalid index at column {}", self.column)
+            }
+            ParseIndicesErrorKind::Overlap => {
+                write!(
+                    f,
+                    "index at column {} overlaps a previous index",
+                    self.column
+                )
+            }
+        }
+    }
+}
+
+impl Error for ParseIndicesError {}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,289 @@
+use std::fmt;
+use std::ops;
+use std::str::FromStr;
+
+use crate::disjoint::find_overlap;
+use crate::error::{GetManyError, ParseIndicesError};
+use crate::slice_index::RawManyIndices;
+use crate::{AnyIndex, DisjointIndices, IndexList, ManyIndices, SliceManyIndices};
+
+/* -------------------------------------------------------------------------- */
+
+/// A dynamic set of indices, which can be parsed from and formatted to text
+/// such as `"0, 3..5, 9..=12, 20.."`.
+///
+/// Every index is stored as an [`AnyIndex`], so a single index `i` borrows the item itself.
+///
+/// ```
+/// # use slice_get_many_poc::{AnyOutput, DisjointIndices, IndexSet, SliceExt};
+/// let mut array = [0, 1, 2, 3, 4, 5, 6, 7];
+///
+/// let indices: DisjointIndices<IndexSet> = "0, 3..5, 6..".parse().unwrap();
+/// let parts = array.get_many_mut_poc(indices).unwrap();
+/// assert_eq!(parts[0], AnyOutput::Item(&mut 0));
+/// assert_eq!(parts[1], AnyOutput::Slice(&mut [3, 4]));
+/// assert_eq!(parts[2], AnyOutput::Slice(&mut [6, 7]));
+///
+/// assert!("0, 3..5, 4..".parse::<DisjointIndices<IndexSet>>().is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IndexSet(pub Vec<AnyIndex>);
+
+/* -------------------------------------------------------------------------- */
+
+/// Parses the indices of `s`, along with the column at which each of them starts.
+fn parse_with_columns(s: &str) -> Result<(IndexSet, Vec<usize>), ParseIndicesError> {
+    let mut indices = Vec::new();
+    let mut columns = Vec::new();
+
+    // NOTE: a blank string is an empty set, not a single invalid index.
+    if s.trim().is_empty() {
+        return Ok((IndexSet(indices), columns));
+    }
+
+    let mut offset = 0;
+    for part in s.split(',') {
+        let start = offset + (part.len() - part.trim_start().len());
+        let column = s[..start].chars().count() + 1;
+        offset += part.len() + 1;
+
+        let index = parse_index(part.trim()).ok_or(ParseIndicesError::invalid_index(column))?;
+        indices.push(index);
+        columns.push(column);
+    }
+
+    Ok((IndexSet(indices), columns))
+}
+
+fn parse_index(s: &str) -> Option<AnyIndex> {
+    let Some((start, end)) = s.split_once("..") else {
+        return parse_number(s).map(AnyIndex::Item);
+    };
+
+    let start = match start.trim_end() {
+        "" => None,
+        start => Some(parse_number(start)?),
+    };
+
+    let index = match (start, end.strip_prefix('=')) {
+        (Some(start), Some(end)) => AnyIndex::from(start..=parse_number(end.trim_start())?),
+        (None, Some(end)) => AnyIndex::from(..=parse_number(end.trim_start())?),
+        (Some(start), None) => match end.trim_start() {
+            "" => AnyIndex::from(start..),
+            end => AnyIndex::from(start..parse_number(end)?),
+        },
+        (None, None) => match end.trim_start() {
+            "" => AnyIndex::RangeFull,
+            end => AnyIndex::from(..parse_number(end)?),
+        },
+    };
+
+    Some(index)
+}
+
+fn parse_number(s: &str) -> Option<usize> {
+    // NOTE: `usize::from_str` also accepts a leading `+`.
+    if !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit()) {
+        s.parse().ok()
+    } else {
+        None
+    }
+}
+
+impl FromStr for IndexSet {
+    type Err = ParseIndicesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_with_columns(s).map(|(indices, _)| indices)
+    }
+}
+
+impl FromStr for DisjointIndices<IndexSet> {
+    type Err = ParseIndicesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (indices, columns) = parse_with_columns(s)?;
+
+        match find_overlap(&indices.0) {
+            Some((i, j)) => Err(ParseIndicesError::overlap(columns[i.max(j)])),
+            // SAFETY: no index overlaps another one.
+            None => Ok(unsafe { DisjointIndices::new_unchecked(indices) }),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn fmt_index(f: &mut fmt::Formatter<'_>, index: &AnyIndex) -> fmt::Result {
+    // NOTE: the text format is the one of `Debug` for ranges, which also shows an exhausted range.
+    match index {
+        AnyIndex::Item(idx) => write!(f, "{idx}"),
+        AnyIndex::Range(idx) => write!(f, "{idx:?}"),
+        AnyIndex::RangeInclusive(idx) => write!(f, "{idx:?}"),
+        AnyIndex::RangeFrom(idx) => write!(f, "{idx:?}"),
+        AnyIndex::RangeTo(idx) => write!(f, "{idx:?}"),
+        AnyIndex::RangeToInclusive(idx) => write!(f, "{idx:?}"),
+        AnyIndex::RangeFull => f.write_str(".."),
+    }
+}
+
+impl fmt::Display for IndexSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, index) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            fmt_index(f, index)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<I: fmt::Display> fmt::Display for DisjointIndices<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.get_ref().fmt(f)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+unsafe impl ManyIndices for IndexSet {
+    fn is_disjoint(&self) -> bool {
+        self.0.is_disjoint()
+    }
+
+    fn is_in_bounds(&self, bounds: ops::RangeTo<usize>) -> bool {
+        self.0.is_in_bounds(bounds)
+    }
+
+    fn is_disjoint_and_in_bounds(&self, bounds: ops::RangeTo<usize>) -> bool {
+        self.0.is_disjoint_and_in_bounds(bounds)
+    }
+
+    fn check_in_bounds(&self, bounds: ops::RangeTo<usize>) -> Result<(), GetManyError> {
+        self.0.check_in_bounds(bounds)
+    }
+
+    fn check_disjoint_and_in_bounds(
+        &self,
+        bounds: ops::RangeTo<usize>,
+    ) -> Result<(), GetManyError> {
+        self.0.check_disjoint_and_in_bounds(bounds)
+    }
+
+    fn check_char_boundaries(&self, s: &str) -> Result<(), GetManyError> {
+        self.0.check_char_boundaries(s)
+    }
+
+    fn fmt_index(&self) -> Option<String> {
+        self.0.fmt_index()
+    }
+}
+
+impl<'slice, Slice: ?Sized> SliceManyIndices<'slice, Slice> for IndexSet
+where
+    Vec<AnyIndex>: SliceManyIndices<'slice, Slice>,
+{
+    type Output = <Vec<AnyIndex> as SliceManyIndices<'slice, Slice>>::Output;
+
+    unsafe fn get_many_unchecked_mut(self, slice: &'slice mut Slice) -> Self::Output {
+        // SAFETY: contract uphold by the caller.
+        unsafe { self.0.get_many_unchecked_mut(slice) }
+    }
+}
+
+impl<'slice, Slice: ?Sized> RawManyIndices<'slice, Slice> for IndexSet
+where
+    Vec<AnyIndex>: RawManyIndices<'slice, Slice>,
+{
+    unsafe fn get_many_unchecked_raw(self, slice: *mut Slice) -> Self::Output {
+        // SAFETY: contract uphold by the caller.
+        unsafe { self.0.get_many_unchecked_raw(slice) }
+    }
+}
+
+unsafe impl IndexList for IndexSet {
+    type Index = AnyIndex;
+
+    fn as_index_slice(&self) -> &[Self::Index] {
+        &self.0
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use crate::error::ParseIndicesErrorKind;
+    use crate::{AnyIndex, DisjointIndices};
+
+    use super::IndexSet;
+
+    #[test]
+    fn parse() {
+        let indices: IndexSet = "0, 3..5, 9..=12, 20.., ..2, ..=4, ..".parse().unwrap();
+        assert_eq!(
+            indices.0,
+            [
+                AnyIndex::Item(0),
+                AnyIndex::from(3..5),
+                AnyIndex::from(9..=12),
+                AnyIndex::from(20..),
+                AnyIndex::from(..2),
+                AnyIndex::from(..=4),
+                AnyIndex::RangeFull,
+            ]
+        );
+
+        assert_eq!("  ".parse::<IndexSet>().unwrap().0, []);
+        assert_eq!(
+            "1 .. 2".parse::<IndexSet>().unwrap().0,
+            [AnyIndex::from(1..2)]
+        );
+    }
+
+    #[test]
+    fn parse_error() {
+        let err = "0, 3..x".parse::<IndexSet>().unwrap_err();
+        assert_eq!(err.kind(), ParseIndicesErrorKind::InvalidIndex);
+        assert_eq!(err.column(), 4);
+
+        assert_eq!("0,,1".parse::<IndexSet>().unwrap_err().column(), 3);
+        assert_eq!("0,\u{a0}1, +1".parse::<IndexSet>().unwrap_err().column(), 7);
+        assert_eq!("0, 3..=".parse::<IndexSet>().unwrap_err().column(), 4);
+        assert_eq!("0, 3...5".parse::<IndexSet>().unwrap_err().column(), 4);
+        assert!("99999999999999999999999".parse::<IndexSet>().is_err());
+    }
+
+    #[test]
+    fn parse_disjoint() {
+        assert!("0, 3..5, 5..=7, 8.."
+            .parse::<DisjointIndices<IndexSet>>()
+            .is_ok());
+
+        let err = "0, 3..5, 9, 4"
+            .parse::<DisjointIndices<IndexSet>>()
+            .unwrap_err();
+        assert_eq!(err.kind(), ParseIndicesErrorKind::Overlap);
+        assert_eq!(err.column(), 13);
+    }
+
+    #[test]
+    fn display() {
+        let text = "0, 3..5, 9..=12, 20.., ..2, ..=4, ..";
+        assert_eq!(text.parse::<IndexSet>().unwrap().to_string(), text);
+
+        let indices = IndexSet(vec![AnyIndex::Item(3), AnyIndex::from(3..=3)]);
+        assert_eq!(indices.to_string(), "3, 3..=3");
+        assert_eq!("3, 3..=3".parse::<IndexSet>().unwrap(), indices);
+
+        let indices = IndexSet(vec![AnyIndex::from(usize::MAX..usize::MAX)]);
+        assert_eq!(indices.to_string().parse::<IndexSet>().unwrap(), indices);
+
+        let indices: DisjointIndices<IndexSet> = "1,4..=5".parse().unwrap();
+        assert_eq!(indices.to_string(), "1, 4..=5");
+    }
+}
+
+/* -------------------------------------------------------------------------- */
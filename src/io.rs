@@ -0,0 +1,244 @@
+use std::io::{IoSlice, IoSliceMut};
+use std::slice;
+
+use crate::{AnyOutput, ManyIndices, SliceExt, SliceManyIndices};
+
+/* -------------------------------------------------------------------------- */
+
+/// The references to many ranges of a byte buffer, which can be turned into buffers for vectored I/O.
+///
+/// Implemented for the outputs of [`get_many_mut_poc`](SliceExt::get_many_mut_poc) on `[u8]`,
+/// so every set of indices it accepts also gives buffers, nested the same way.
+///
+/// See [`Read::read_vectored`](std::io::Read::read_vectored) and
+/// [`Write::write_vectored`](std::io::Write::write_vectored).
+pub trait IoSliceOutput<'buf> {
+    type IoSlices;
+    type IoSlicesMut;
+
+    /// Returns the buffers to write from.
+    fn into_io_slices(self) -> Self::IoSlices;
+
+    /// Returns the buffers to read into.
+    fn into_io_slices_mut(self) -> Self::IoSlicesMut;
+}
+
+impl<'buf> IoSliceOutput<'buf> for &'buf mut [u8] {
+    type IoSlices = IoSlice<'buf>;
+    type IoSlicesMut = IoSliceMut<'buf>;
+
+    #[inline]
+    fn into_io_slices(self) -> Self::IoSlices {
+        IoSlice::new(self)
+    }
+
+    #[inline]
+    fn into_io_slices_mut(self) -> Self::IoSlicesMut {
+        IoSliceMut::new(self)
+    }
+}
+
+// NOTE: a single byte is a buffer of length 1, like `AnyOutput::into_slice` does.
+impl<'buf> IoSliceOutput<'buf> for &'buf mut u8 {
+    type IoSlices = IoSlice<'buf>;
+    type IoSlicesMut = IoSliceMut<'buf>;
+
+    #[inline]
+    fn into_io_slices(self) -> Self::IoSlices {
+        slice::from_mut(self).into_io_slices()
+    }
+
+    #[inline]
+    fn into_io_slices_mut(self) -> Self::IoSlicesMut {
+        slice::from_mut(self).into_io_slices_mut()
+    }
+}
+
+impl<'buf> IoSliceOutput<'buf> for AnyOutput<'buf, u8> {
+    type IoSlices = IoSlice<'buf>;
+    type IoSlicesMut = IoSliceMut<'buf>;
+
+    #[inline]
+    fn into_io_slices(self) -> Self::IoSlices {
+        self.into_slice().into_io_slices()
+    }
+
+    #[inline]
+    fn into_io_slices_mut(self) -> Self::IoSlicesMut {
+        self.into_slice().into_io_slices_mut()
+    }
+}
+
+impl<'buf, O: IoSliceOutput<'buf>> IoSliceOutput<'buf> for Option<O> {
+    type IoSlices = Option<O::IoSlices>;
+    type IoSlicesMut = Option<O::IoSlicesMut>;
+
+    fn into_io_slices(self) -> Self::IoSlices {
+        self.map(O::into_io_slices)
+    }
+
+    fn into_io_slices_mut(self) -> Self::IoSlicesMut {
+        self.map(O::into_io_slices_mut)
+    }
+}
+
+impl<'buf, O: IoSliceOutput<'buf>, const N: usize> IoSliceOutput<'buf> for [O; N] {
+    type IoSlices = [O::IoSlices; N];
+    type IoSlicesMut = [O::IoSlicesMut; N];
+
+    fn into_io_slices(self) -> Self::IoSlices {
+        self.map(O::into_io_slices)
+    }
+
+    fn into_io_slices_mut(self) -> Self::IoSlicesMut {
+        self.map(O::into_io_slices_mut)
+    }
+}
+
+impl<'buf, O: IoSliceOutput<'buf>> IoSliceOutput<'buf> for Vec<O> {
+    type IoSlices = Vec<O::IoSlices>;
+    type IoSlicesMut = Vec<O::IoSlicesMut>;
+
+    fn into_io_slices(self) -> Self::IoSlices {
+        self.into_iter().map(O::into_io_slices).collect()
+    }
+
+    fn into_io_slices_mut(self) -> Self::IoSlicesMut {
+        self.into_iter().map(O::into_io_slices_mut).collect()
+    }
+}
+
+macro_rules! impl_tuple {
+    ( $($O:ident)+ ) => {
+        #[allow(non_snake_case)]
+        impl<'buf, $($O,)*> IoSliceOutput<'buf> for ($($O,)*)
+        where
+            $( $O: IoSliceOutput<'buf>, )*
+        {
+            type IoSlices = ( $( $O::IoSlices, )* );
+            type IoSlicesMut = ( $( $O::IoSlicesMut, )* );
+
+            fn into_io_slices(self) -> Self::IoSlices {
+                let ( $( $O, )* ) = self;
+                ( $( $O.into_io_slices(), )* )
+            }
+
+            fn into_io_slices_mut(self) -> Self::IoSlicesMut {
+                let ( $( $O, )* ) = self;
+                ( $( $O.into_io_slices_mut(), )* )
+            }
+        }
+    };
+}
+
+for_each_tuple!(impl_tuple);
+
+/* -------------------------------------------------------------------------- */
+
+/// An extension trait to add the vectored I/O methods to byte slices.
+pub trait IoSliceExt {
+    /// Returns buffers for [`Write::write_vectored`](std::io::Write::write_vectored)
+    /// over many ranges at once, or [`None`] if `indices` is not disjoint or not in bounds.
+    ///
+    /// The buffer is borrowed mutably, since the buffers are taken from
+    /// [`get_many_mut_poc`](SliceExt::get_many_mut_poc).
+    fn get_many_io_slices<'buf, I>(
+        &'buf mut self,
+        indices: I,
+    ) -> Option<<I::Output as IoSliceOutput<'buf>>::IoSlices>
+    where
+        I: ManyIndices + SliceManyIndices<'buf, [u8], Output: IoSliceOutput<'buf>>;
+
+    /// Returns buffers for [`Read::read_vectored`](std::io::Read::read_vectored)
+    /// over many ranges at once, or [`None`] if `indices` is not disjoint or not in bounds.
+    fn get_many_io_slices_mut<'buf, I>(
+        &'buf mut self,
+        indices: I,
+    ) -> Option<<I::Output as IoSliceOutput<'buf>>::IoSlicesMut>
+    where
+        I: ManyIndices + SliceManyIndices<'buf, [u8], Output: IoSliceOutput<'buf>>;
+}
+
+impl IoSliceExt for [u8] {
+    fn get_many_io_slices<'buf, I>(
+        &'buf mut self,
+        indices: I,
+    ) -> Option<<I::Output as IoSliceOutput<'buf>>::IoSlices>
+    where
+        I: ManyIndices + SliceManyIndices<'buf, [u8], Output: IoSliceOutput<'buf>>,
+    {
+        self.get_many_mut_poc(indices)
+            .map(IoSliceOutput::into_io_slices)
+    }
+
+    fn get_many_io_slices_mut<'buf, I>(
+        &'buf mut self,
+        indices: I,
+    ) -> Option<<I::Output as IoSliceOutput<'buf>>::IoSlicesMut>
+    where
+        I: ManyIndices + SliceManyIndices<'buf, [u8], Output: IoSliceOutput<'buf>>,
+    {
+        self.get_many_mut_poc(indices)
+            .map(IoSliceOutput::into_io_slices_mut)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+
+    use crate::IndexSet;
+
+    use super::IoSliceExt;
+
+    #[test]
+    fn read_vectored() {
+        let mut frame = [0u8; 12];
+
+        let mut bufs = frame.get_many_io_slices_mut([0..2, 4..8, 10..12]).unwrap();
+        let n = (&b"hello world!"[..]).read_vectored(&mut bufs).unwrap();
+
+        assert_eq!(n, 8);
+        assert_eq!(&frame, b"he\0\0llo \0\0wo");
+
+        assert!(frame.get_many_io_slices_mut(vec![0..2, 1..3]).is_none());
+        assert!(frame.get_many_io_slices_mut([0..2, 10..13]).is_none());
+    }
+
+    #[test]
+    fn write_vectored() {
+        let mut frame = *b"hello world!";
+        let mut out = Vec::new();
+
+        let bufs = frame.get_many_io_slices(vec![6..11, 5..6, 0..5]).unwrap();
+        let n = out.write_vectored(&bufs).unwrap();
+
+        assert_eq!(n, 11);
+        assert_eq!(out, b"world hello");
+    }
+
+    #[test]
+    fn nested_indices() {
+        let mut frame = *b"hello world!";
+        let set: IndexSet = "0..5, 11".parse().unwrap();
+
+        let bufs = frame.get_many_io_slices(set).unwrap();
+        assert_eq!(
+            bufs.iter().map(|buf| &buf[..]).collect::<Vec<_>>(),
+            [&b"hello"[..], b"!"]
+        );
+
+        let (head, [a, b], c) = frame
+            .get_many_io_slices((..1, [Some(6..7), None], None::<usize>))
+            .unwrap();
+        assert_eq!(&*head, b"h");
+        assert_eq!(a.as_deref(), Some(&b"w"[..]));
+        assert!(b.is_none() && c.is_none());
+
+        assert!(frame.get_many_io_slices((0..2, [1, 3])).is_none());
+    }
+}
+
+/* -------------------------------------------------------------------------- */
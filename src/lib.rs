@@ -1,16 +1,69 @@
+#![feature(array_try_map)]
 #![feature(slice_ptr_get)]
+#![feature(slice_range)]
 
+/// Invokes the macro `$m` once for each tuple arity, from 12 elements down to 1,
+/// with one identifier per element of the tuple.
+macro_rules! for_each_tuple {
+    ($m:ident) => {
+        for_each_tuple!(@recursive $m I0 I1 I2 I3 I4 I5 I6 I7 I8 I9 I10 I11);
+    };
+
+    (@recursive $m:ident $first:ident $($next:ident)*) => {
+        $m!($first $($next)*);
+        for_each_tuple!(@recursive $m $($next)*);
+    };
+
+    (@recursive $m:ident) => {/* stop condition */};
+}
+
+mod access;
+mod any;
+mod as_index;
+mod cell;
+mod const_indices;
+mod deque;
 mod disjoint;
+mod each;
+mod error;
+mod index_set;
+mod io;
+mod lock;
+mod option;
 mod overlap;
+mod parallel;
+mod set_ops;
+mod shift;
 mod slice_index;
+mod sorted;
+mod split;
 
+pub use access::{Mut, Shared};
+pub use any::{AnyIndex, AnyOutput};
+pub use as_index::AsIndex;
+pub use cell::{SliceCell, SliceRef, SliceRefMut};
+pub use const_indices::ConstIndices;
+pub use deque::VecDequeExt;
 pub use disjoint::{DisjointIndices, ManyIndices};
-pub use overlap::IndexOverlap;
-pub use slice_index::SliceManyIndices;
+pub use each::{CheckIndex, SliceEachIndices};
+pub use error::{GetManyError, GetManyErrorKind, ParseIndicesError, ParseIndicesErrorKind};
+pub use index_set::IndexSet;
+pub use io::{IoSliceExt, IoSliceOutput};
+pub use lock::{Claim, SliceLock};
+pub use overlap::{IndexOverlap, IndexSpan, Span};
+pub use parallel::par_map_many_mut;
+pub use shift::ShiftIndices;
+pub use slice_index::{SliceManyIndices, SliceSingleIndex};
+pub use sorted::{IndexList, SortedIndices};
+pub use split::{SliceSplitExt, SplitPoints};
 
 /* -------------------------------------------------------------------------- */
 
 /// An extension trait to add the `get_many_mut` methods to slice.
+///
+/// Types that dereference to a slice or a `str`, like `Vec<T>`, `Box<[T]>` and `String`,
+/// get these methods through auto-deref, with the same checks.
+/// See [`VecDequeExt`] for `VecDeque<T>`.
 pub trait SliceExt {
     /// Returns mutable references to many indices at once,
     /// or [`None`] if `indices` is not disjoint or not in bounds.
@@ -18,6 +71,48 @@ pub trait SliceExt {
     where
         I: ManyIndices + SliceManyIndices<'slice, Self>;
 
+    /// Returns mutable references to many indices at once,
+    /// or a [`GetManyError`] describing why `indices` is not disjoint or not in bounds.
+    fn try_get_many_mut_poc<'slice, I>(
+        &'slice mut self,
+        indices: I,
+    ) -> Result<I::Output, GetManyError>
+    where
+        I: ManyIndices + SliceManyIndices<'slice, Self>;
+
+    /// Returns mutable references to many indices at once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `indices` is not disjoint or not in bounds,
+    /// with a message describing the offending indices.
+    #[track_caller]
+    fn index_many_mut_poc<'slice, I>(&'slice mut self, indices: I) -> I::Output
+    where
+        I: ManyIndices + SliceManyIndices<'slice, Self>,
+    {
+        match self.try_get_many_mut_poc(indices) {
+            Ok(output) => output,
+            Err(err) => get_many_fail(err),
+        }
+    }
+
+    /// Returns a mutable reference to each index that is in bounds, or [`None`] for the others,
+    /// or returns [`None`] for the whole batch if `indices` is not disjoint.
+    fn get_each_mut_poc<'slice, I>(&'slice mut self, indices: I) -> Option<I::Output>
+    where
+        I: ManyIndices + SliceEachIndices<'slice, Self>,
+    {
+        // NOTE: indices that are out of bounds still count as overlapping, so that
+        // the result does not depend on the length of the slice.
+        if !indices.is_disjoint() {
+            return None;
+        }
+
+        // SAFETY: we have checked that `indices` is disjoint.
+        Some(unsafe { indices.get_each_unchecked_mut(self) })
+    }
+
     /// Returns mutable references to many indices at once, without doing any checks.
     ///
     /// For a safe alternative see [`get_many_mut_poc`].
@@ -26,11 +121,20 @@ pub trait SliceExt {
     ///
     /// Calling this method with overlapping or out-of-bounds indices is *undefined behavior*
     /// even if the resulting references are not used.
+    /// For `str`, so is calling it with indices that are not on UTF-8 char boundaries.
     unsafe fn get_many_mut_unchecked_poc<'slice, I>(&'slice mut self, indices: I) -> I::Output
     where
         I: SliceManyIndices<'slice, Self>;
 }
 
+// NOTE: out of line to keep the panicking path away from the callers, like std does for slice indexing.
+#[cold]
+#[inline(never)]
+#[track_caller]
+fn get_many_fail(err: GetManyError) -> ! {
+    panic!("{err}")
+}
+
 impl<T> SliceExt for [T] {
     fn get_many_mut_poc<'slice, I>(&'slice mut self, indices: I) -> Option<I::Output>
     where
@@ -44,6 +148,19 @@ impl<T> SliceExt for [T] {
         Some(unsafe { SliceManyIndices::get_many_unchecked_mut(indices, self) })
     }
 
+    fn try_get_many_mut_poc<'slice, I>(
+        &'slice mut self,
+        indices: I,
+    ) -> Result<I::Output, GetManyError>
+    where
+        I: ManyIndices + SliceManyIndices<'slice, Self>,
+    {
+        indices.check_disjoint_and_in_bounds(..self.len())?;
+
+        // SAFETY: we have checked that `indices` is disjoint and in bounds.
+        Ok(unsafe { SliceManyIndices::get_many_unchecked_mut(indices, self) })
+    }
+
     unsafe fn get_many_mut_unchecked_poc<'slice, I>(&'slice mut self, indices: I) -> I::Output
     where
         I: SliceManyIndices<'slice, Self>,
@@ -58,14 +175,30 @@ impl SliceExt for str {
     where
         I: ManyIndices + SliceManyIndices<'slice, Self>,
     {
-        if !indices.is_disjoint_and_in_bounds(..self.len()) {
+        if !indices.is_disjoint_and_in_bounds(..self.len())
+            || indices.check_char_boundaries(self).is_err()
+        {
             return None;
         }
 
-        // SAFETY: we have checked that `indices` is disjoint and in bounds.
+        // SAFETY: we have checked that `indices` is disjoint, in bounds and on char boundaries.
         Some(unsafe { SliceManyIndices::get_many_unchecked_mut(indices, self) })
     }
 
+    fn try_get_many_mut_poc<'slice, I>(
+        &'slice mut self,
+        indices: I,
+    ) -> Result<I::Output, GetManyError>
+    where
+        I: ManyIndices + SliceManyIndices<'slice, Self>,
+    {
+        indices.check_disjoint_and_in_bounds(..self.len())?;
+        indices.check_char_boundaries(self)?;
+
+        // SAFETY: we have checked that `indices` is disjoint, in bounds and on char boundaries.
+        Ok(unsafe { SliceManyIndices::get_many_unchecked_mut(indices, self) })
+    }
+
     unsafe fn get_many_mut_unchecked_poc<'slice, I>(&'slice mut self, indices: I) -> I::Output
     where
         I: SliceManyIndices<'slice, Self>,
@@ -76,3 +209,26 @@ impl SliceExt for str {
 }
 
 /* -------------------------------------------------------------------------- */
+
+/// An extension trait to add the `get_many_mut` methods to fixed-size arrays.
+pub trait ArrayExt<T, const N: usize> {
+    /// Returns mutable references to many indices at once.
+    ///
+    /// The indices are checked at compile time by [`const_indices!`],
+    /// so no check is done at runtime.
+    fn get_many_mut_const<'array, I>(&'array mut self, indices: ConstIndices<I, N>) -> I::Output
+    where
+        I: SliceManyIndices<'array, [T]>;
+}
+
+impl<T, const N: usize> ArrayExt<T, N> for [T; N] {
+    fn get_many_mut_const<'array, I>(&'array mut self, indices: ConstIndices<I, N>) -> I::Output
+    where
+        I: SliceManyIndices<'array, [T]>,
+    {
+        // SAFETY: `ConstIndices` asserts that the indices are disjoint and in bounds of `..N`.
+        unsafe { SliceManyIndices::get_many_unchecked_mut(indices.get(), self.as_mut_slice()) }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,210 @@
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut, Range, RangeBounds};
+use std::ptr::NonNull;
+use std::slice;
+use std::sync::{Condvar, Mutex, MutexGuard};
+
+use crate::overlap::IndexOverlap;
+
+/* -------------------------------------------------------------------------- */
+
+/// A mutable slice whose regions can be claimed concurrently by many threads.
+///
+/// A region may not overlap any other live claim. A single index `i` is claimed as `i..=i`.
+pub struct SliceLock<'a, T> {
+    slice: NonNull<[T]>,
+    claims: Claims,
+    _marker: PhantomData<&'a mut [T]>,
+}
+
+// SAFETY: the claims are disjoint, so the items are only accessed by one thread at a time.
+unsafe impl<T: Send> Send for SliceLock<'_, T> {}
+// SAFETY: the claims are disjoint, so the items are only accessed by one thread at a time.
+unsafe impl<T: Send> Sync for SliceLock<'_, T> {}
+
+/// The regions claimed in a [`SliceLock`].
+struct Claims {
+    live: Mutex<Vec<Range<usize>>>,
+    released: Condvar,
+}
+
+impl Claims {
+    fn lock(&self) -> MutexGuard<'_, Vec<Range<usize>>> {
+        // NOTE: the list of claims is always left consistent, so it can be used even if a thread panicked.
+        self.live.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+impl<'a, T> SliceLock<'a, T> {
+    pub fn new(slice: &'a mut [T]) -> Self {
+        Self {
+            slice: NonNull::from(slice),
+            claims: Claims {
+                live: Mutex::new(Vec::new()),
+                released: Condvar::new(),
+            },
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the length of the underlying slice.
+    pub fn len(&self) -> usize {
+        self.slice.len()
+    }
+
+    /// Returns `true` if the underlying slice is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Claims the items in `range`,
+    /// or returns [`None`] if it is out of bounds or overlaps a live claim.
+    pub fn try_claim<R>(&self, range: R) -> Option<Claim<'_, T>>
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = slice::try_range(range, ..self.len())?;
+        let mut live = self.claims.lock();
+
+        if live.iter().any(|claim| range.overlap(claim)) {
+            return None;
+        }
+
+        live.push(range.clone());
+        drop(live);
+
+        // SAFETY: `range` is in bounds and no live claim overlaps it.
+        Some(unsafe { self.claim_unchecked(range) })
+    }
+
+    /// Claims the items in `range`, blocking the current thread until no live claim overlaps it,
+    /// or returns [`None`] if it is out of bounds.
+    ///
+    /// This deadlocks if the current thread itself holds a claim that overlaps `range`,
+    /// since that claim is never released while the thread waits.
+    /// Use [`try_claim`](SliceLock::try_claim) when the thread may already hold such a claim.
+    pub fn claim<R>(&self, range: R) -> Option<Claim<'_, T>>
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = slice::try_range(range, ..self.len())?;
+        let mut live = self.claims.lock();
+
+        while live.iter().any(|claim| range.overlap(claim)) {
+            live = self
+                .claims
+                .released
+                .wait(live)
+                .unwrap_or_else(|err| err.into_inner());
+        }
+
+        live.push(range.clone());
+        drop(live);
+
+        // SAFETY: `range` is in bounds and no live claim overlaps it.
+        Some(unsafe { self.claim_unchecked(range) })
+    }
+
+    /// # Safety
+    ///
+    /// `range` must be in bounds and recorded as a live claim that overlaps no other.
+    unsafe fn claim_unchecked(&self, range: Range<usize>) -> Claim<'_, T> {
+        // SAFETY: contract uphold by the caller.
+        let items = unsafe { &mut *self.slice.as_ptr().get_unchecked_mut(range.clone()) };
+
+        Claim {
+            items,
+            claims: &self.claims,
+            range,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A claim on a region of a [`SliceLock`], released when dropped.
+pub struct Claim<'b, T> {
+    items: &'b mut [T],
+    claims: &'b Claims,
+    range: Range<usize>,
+}
+
+impl<T> Drop for Claim<'_, T> {
+    fn drop(&mut self) {
+        let mut live = self.claims.lock();
+
+        if let Some(i) = live.iter().position(|claim| *claim == self.range) {
+            live.swap_remove(i);
+        }
+
+        drop(live);
+        self.claims.released.notify_all();
+    }
+}
+
+impl<T> Deref for Claim<'_, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.items
+    }
+}
+
+impl<T> DerefMut for Claim<'_, T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.items
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::SliceLock;
+
+    #[test]
+    fn try_claim() {
+        let mut array = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let lock = SliceLock::new(&mut array);
+
+        let a = lock.try_claim(..4).unwrap();
+        assert!(lock.try_claim(3..=3).is_none());
+        assert!(lock.try_claim(8..11).is_none());
+
+        let b = lock.try_claim(4..).unwrap();
+        drop(a);
+        assert!(lock.try_claim(3..=3).is_some());
+        drop(b);
+    }
+
+    #[test]
+    fn concurrent_claims() {
+        let mut array = [0; 64];
+        let lock = SliceLock::new(&mut array);
+
+        thread::scope(|s| {
+            // NOTE: the last chunk is kept to count the claims of the whole slice.
+            for chunk in 0..7 {
+                let lock = &lock;
+                s.spawn(move || {
+                    let mut items = lock.claim(chunk * 8..(chunk + 1) * 8).unwrap();
+                    items.fill(chunk);
+                    drop(items);
+
+                    // NOTE: every thread also waits for the whole slice at some point.
+                    let mut all = lock.claim(..).unwrap();
+                    all[63] += 1;
+                });
+            }
+        });
+
+        assert_eq!(array[0], 0);
+        assert_eq!(array[8], 1);
+        assert_eq!(array[55], 6);
+        assert_eq!(array[63], 7);
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,116 @@
+use std::ops::{self, RangeBounds};
+
+use crate::error::GetManyError;
+use crate::overlap::{IndexSpan, Span};
+use crate::{ManyIndices, ShiftIndices, SliceSingleIndex};
+
+/* -------------------------------------------------------------------------- */
+
+// NOTE: a `None` index borrows no item, so it is always in bounds and never overlaps.
+
+unsafe impl<I: ManyIndices> ManyIndices for Option<I> {
+    fn is_disjoint(&self) -> bool {
+        self.as_ref().is_none_or(I::is_disjoint)
+    }
+
+    fn is_in_bounds(&self, bounds: ops::RangeTo<usize>) -> bool {
+        self.as_ref().is_none_or(|idx| idx.is_in_bounds(bounds))
+    }
+
+    fn is_disjoint_and_in_bounds(&self, bounds: ops::RangeTo<usize>) -> bool {
+        self.as_ref()
+            .is_none_or(|idx| idx.is_disjoint_and_in_bounds(bounds))
+    }
+
+    fn check_in_bounds(&self, bounds: ops::RangeTo<usize>) -> Result<(), GetManyError> {
+        match self {
+            Some(idx) => idx.check_in_bounds(bounds),
+            None => Ok(()),
+        }
+    }
+
+    fn check_disjoint_and_in_bounds(
+        &self,
+        bounds: ops::RangeTo<usize>,
+    ) -> Result<(), GetManyError> {
+        match self {
+            Some(idx) => idx.check_disjoint_and_in_bounds(bounds),
+            None => Ok(()),
+        }
+    }
+
+    fn check_char_boundaries(&self, s: &str) -> Result<(), GetManyError> {
+        match self {
+            Some(idx) => idx.check_char_boundaries(s),
+            None => Ok(()),
+        }
+    }
+
+    fn fmt_index(&self) -> Option<String> {
+        match self {
+            Some(idx) => Some(format!("Some({})", idx.fmt_index()?)),
+            None => Some(String::from("None")),
+        }
+    }
+}
+
+unsafe impl<I: IndexSpan> IndexSpan for Option<I> {
+    #[inline]
+    fn span(&self) -> Span {
+        match self {
+            Some(idx) => idx.span(),
+            None => Span::from_range(&(0..0)),
+        }
+    }
+}
+
+unsafe impl<'slice, Slice: ?Sized, I> SliceSingleIndex<'slice, Slice> for Option<I>
+where
+    I: SliceSingleIndex<'slice, Slice>,
+{
+    type Output = Option<I::Output>;
+
+    #[inline]
+    unsafe fn get_unchecked_raw(self, slice: *mut Slice) -> Self::Output {
+        // SAFETY: contract uphold by the caller.
+        self.map(|idx| unsafe { idx.get_unchecked_raw(slice) })
+    }
+}
+
+impl<I: ShiftIndices> ShiftIndices for Option<I> {
+    type Output = Option<I::Output>;
+
+    fn offset(self, k: usize) -> Option<Self::Output> {
+        match self {
+            Some(idx) => idx.offset(k).map(Some),
+            None => Some(None),
+        }
+    }
+
+    fn rebase<R: RangeBounds<usize>>(self, range: R) -> Option<Self::Output> {
+        match self {
+            Some(idx) => idx.rebase(range).map(Some),
+            None => Some(None),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use crate::{IndexOverlap, ManyIndices};
+
+    #[test]
+    fn none_index() {
+        assert!(None::<usize>.is_in_bounds(..0));
+        assert!(!Some(3).is_in_bounds(..3));
+        assert!(!None::<usize>.overlap(&None::<usize>));
+        assert!(!None::<usize>.overlap(&(..)));
+        assert!(Some(2).overlap(&(..)));
+        assert!([None, Some(0), None].is_disjoint());
+        assert!(![Some(1..3), None, Some(2..4)].is_disjoint());
+    }
+}
+
+/* -------------------------------------------------------------------------- */
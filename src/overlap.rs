@@ -1,10 +1,15 @@
-use std::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+use std::ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+use crate::disjoint::{resolve_bounds, resolve_inclusive};
+use crate::{ManyIndices, Shared};
 
 /* -------------------------------------------------------------------------- */
 
 /// An operation that check if two indices or range of indices overlap each other
 /// i.e. if they may borrow the same item from a slice.
 ///
+/// Every index that implements [`IndexSpan`] overlaps another one if their spans intersect.
+///
 /// # Safety
 ///
 /// The implementation must be correct.
@@ -12,392 +17,323 @@ pub unsafe trait IndexOverlap<T> {
     fn overlap(&self, other: &T) -> bool;
 }
 
-/* -------------------------------------------------------------------------- */
-
-unsafe impl IndexOverlap<RangeFull> for RangeFull {
-    #[inline]
-    fn overlap(&self, _other: &RangeFull) -> bool {
-        true
-    }
-}
-
-unsafe impl IndexOverlap<usize> for RangeFull {
-    #[inline]
-    fn overlap(&self, _other: &usize) -> bool {
-        true
-    }
+/// The canonical form of an index: the half-open interval of items it may borrow from a slice.
+///
+/// It is also used to order indices so that their overlap can be checked without comparing every pair.
+///
+/// # Safety
+///
+/// The returned span must contain every item the index may borrow.
+pub unsafe trait IndexSpan {
+    fn span(&self) -> Span;
 }
 
-unsafe impl IndexOverlap<Range<usize>> for RangeFull {
-    #[inline]
-    fn overlap(&self, _other: &Range<usize>) -> bool {
-        true
-    }
+/// A half-open interval of items, whose `end` is [`None`] if it is unbounded.
+///
+/// The span is empty if its end is not greater than its start, in which case the index borrows no item.
+/// An unbounded span is never empty: `usize::MAX` and `usize::MAX..` both span `usize::MAX..`,
+/// since the end of `usize::MAX` cannot be represented, and overlap each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Span {
+    pub start: usize,
+    pub end: Option<usize>,
 }
 
-unsafe impl IndexOverlap<RangeInclusive<usize>> for RangeFull {
+impl Span {
+    /// Creates the bounded span `range`.
     #[inline]
-    fn overlap(&self, _other: &RangeInclusive<usize>) -> bool {
-        true
+    pub const fn from_range(range: &Range<usize>) -> Self {
+        Self {
+            start: range.start,
+            end: Some(range.end),
+        }
     }
-}
 
-unsafe impl IndexOverlap<RangeFrom<usize>> for RangeFull {
+    /// Returns `true` if the span contains no item.
     #[inline]
-    fn overlap(&self, _other: &RangeFrom<usize>) -> bool {
-        true
+    pub const fn is_empty(&self) -> bool {
+        match self.end {
+            Some(end) => self.start >= end,
+            None => false,
+        }
     }
-}
 
-unsafe impl IndexOverlap<RangeTo<usize>> for RangeFull {
+    /// Returns `true` if the span ends after `other`, an unbounded end being after any other one.
     #[inline]
-    fn overlap(&self, _other: &RangeTo<usize>) -> bool {
-        true
+    pub(crate) const fn ends_after(&self, other: &Span) -> bool {
+        match (self.end, other.end) {
+            (None, _) => true,
+            (Some(_), None) => false,
+            (Some(end), Some(other)) => end > other,
+        }
     }
-}
 
-unsafe impl IndexOverlap<RangeToInclusive<usize>> for RangeFull {
+    /// Returns the items of a slice of length `len` covered by the span.
     #[inline]
-    fn overlap(&self, _other: &RangeToInclusive<usize>) -> bool {
-        true
+    pub(crate) fn to_range(self, len: usize) -> Range<usize> {
+        let end = self.end.map_or(len, |end| end.min(len));
+        self.start.min(end)..end
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-unsafe impl IndexOverlap<RangeFull> for usize {
+unsafe impl IndexSpan for usize {
     #[inline]
-    fn overlap(&self, other: &RangeFull) -> bool {
-        // NOTE: reuse the implementation above.
-        other.overlap(self)
+    fn span(&self) -> Span {
+        Span {
+            start: *self,
+            end: self.checked_add(1),
+        }
     }
 }
 
-unsafe impl IndexOverlap<usize> for usize {
+unsafe impl IndexSpan for Range<usize> {
     #[inline]
-    fn overlap(&self, other: &usize) -> bool {
-        self == other
+    fn span(&self) -> Span {
+        Span::from_range(self)
     }
 }
 
-unsafe impl IndexOverlap<Range<usize>> for usize {
+unsafe impl IndexSpan for RangeInclusive<usize> {
     #[inline]
-    fn overlap(&self, other: &Range<usize>) -> bool {
-        other.contains(self)
+    fn span(&self) -> Span {
+        match resolve_inclusive(self) {
+            Some(range) => Span::from_range(&range),
+            None => Span {
+                start: *self.start(),
+                end: None,
+            },
+        }
     }
 }
 
-unsafe impl IndexOverlap<RangeInclusive<usize>> for usize {
+unsafe impl IndexSpan for RangeFrom<usize> {
     #[inline]
-    fn overlap(&self, other: &RangeInclusive<usize>) -> bool {
-        other.contains(self)
+    fn span(&self) -> Span {
+        Span {
+            start: self.start,
+            end: None,
+        }
     }
 }
 
-unsafe impl IndexOverlap<RangeFrom<usize>> for usize {
+unsafe impl IndexSpan for RangeTo<usize> {
     #[inline]
-    fn overlap(&self, other: &RangeFrom<usize>) -> bool {
-        other.contains(self)
-    }
-}
-
-unsafe impl IndexOverlap<RangeTo<usize>> for usize {
-    #[inline]
-    fn overlap(&self, other: &RangeTo<usize>) -> bool {
-        other.contains(self)
-    }
-}
-
-unsafe impl IndexOverlap<RangeToInclusive<usize>> for usize {
-    #[inline]
-    fn overlap(&self, other: &RangeToInclusive<usize>) -> bool {
-        other.contains(self)
-    }
-}
-
-/* -------------------------------------------------------------------------- */
-
-unsafe impl IndexOverlap<RangeFull> for Range<usize> {
-    #[inline]
-    fn overlap(&self, other: &RangeFull) -> bool {
-        // NOTE: reuse the implementation above.
-        other.overlap(self)
-    }
-}
-
-unsafe impl IndexOverlap<usize> for Range<usize> {
-    #[inline]
-    fn overlap(&self, other: &usize) -> bool {
-        // NOTE: reuse the implementation above.
-        other.overlap(self)
+    fn span(&self) -> Span {
+        Span::from_range(&(0..self.end))
     }
 }
 
-unsafe impl IndexOverlap<Range<usize>> for Range<usize> {
+unsafe impl IndexSpan for RangeToInclusive<usize> {
     #[inline]
-    fn overlap(&self, other: &Range<usize>) -> bool {
-        self.contains(&other.start) || other.contains(&self.start)
+    fn span(&self) -> Span {
+        Span {
+            start: 0,
+            end: self.end.checked_add(1),
+        }
     }
 }
 
-unsafe impl IndexOverlap<RangeInclusive<usize>> for Range<usize> {
+unsafe impl IndexSpan for RangeFull {
     #[inline]
-    fn overlap(&self, other: &RangeInclusive<usize>) -> bool {
-        self.contains(other.start()) || other.contains(&self.start)
+    fn span(&self) -> Span {
+        Span {
+            start: 0,
+            end: None,
+        }
     }
 }
 
-unsafe impl IndexOverlap<RangeFrom<usize>> for Range<usize> {
+unsafe impl IndexSpan for (Bound<usize>, Bound<usize>) {
     #[inline]
-    fn overlap(&self, other: &RangeFrom<usize>) -> bool {
-        other.start < self.end
-    }
-}
-
-unsafe impl IndexOverlap<RangeTo<usize>> for Range<usize> {
-    #[inline]
-    fn overlap(&self, other: &RangeTo<usize>) -> bool {
-        other.contains(&self.start)
-    }
-}
-
-unsafe impl IndexOverlap<RangeToInclusive<usize>> for Range<usize> {
-    #[inline]
-    fn overlap(&self, other: &RangeToInclusive<usize>) -> bool {
-        other.contains(&self.start)
+    fn span(&self) -> Span {
+        match resolve_bounds(self) {
+            Some((start, end)) => Span { start, end },
+            // NOTE: a bound that overflows reaches past `usize::MAX`, so the span is unbounded.
+            None => {
+                let start = resolve_bounds(&(self.0, Bound::Unbounded));
+                Span {
+                    start: start.map_or(usize::MAX, |(start, _)| start),
+                    end: None,
+                }
+            }
+        }
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-unsafe impl IndexOverlap<RangeFull> for RangeInclusive<usize> {
-    #[inline]
-    fn overlap(&self, other: &RangeFull) -> bool {
-        // NOTE: reuse the implementation above.
-        other.overlap(self)
-    }
-}
-
-unsafe impl IndexOverlap<usize> for RangeInclusive<usize> {
-    #[inline]
-    fn overlap(&self, other: &usize) -> bool {
-        // NOTE: reuse the implementation above.
-        other.overlap(self)
-    }
-}
-
-unsafe impl IndexOverlap<Range<usize>> for RangeInclusive<usize> {
-    #[inline]
-    fn overlap(&self, other: &Range<usize>) -> bool {
-        // NOTE: reuse the implementation above.
-        other.overlap(self)
-    }
-}
-
-unsafe impl IndexOverlap<RangeInclusive<usize>> for RangeInclusive<usize> {
-    #[inline]
-    fn overlap(&self, other: &RangeInclusive<usize>) -> bool {
-        self.contains(other.start()) || other.contains(self.start())
-    }
-}
-
-unsafe impl IndexOverlap<RangeFrom<usize>> for RangeInclusive<usize> {
-    #[inline]
-    fn overlap(&self, other: &RangeFrom<usize>) -> bool {
-        other.start <= *self.end()
-    }
-}
-
-unsafe impl IndexOverlap<RangeTo<usize>> for RangeInclusive<usize> {
-    #[inline]
-    fn overlap(&self, other: &RangeTo<usize>) -> bool {
-        other.contains(self.start())
-    }
-}
-
-unsafe impl IndexOverlap<RangeToInclusive<usize>> for RangeInclusive<usize> {
+unsafe impl<I, J> IndexOverlap<J> for I
+where
+    I: IndexSpan,
+    J: IndexSpan,
+{
     #[inline]
-    fn overlap(&self, other: &RangeToInclusive<usize>) -> bool {
-        other.contains(self.start())
+    fn overlap(&self, other: &J) -> bool {
+        spans_overlap(&self.span(), &other.span())
     }
 }
 
-/* -------------------------------------------------------------------------- */
-
-unsafe impl IndexOverlap<RangeFull> for RangeFrom<usize> {
-    #[inline]
-    fn overlap(&self, other: &RangeFull) -> bool {
-        // NOTE: reuse the implementation above.
-        other.overlap(self)
-    }
-}
-
-unsafe impl IndexOverlap<usize> for RangeFrom<usize> {
-    #[inline]
-    fn overlap(&self, other: &usize) -> bool {
-        // NOTE: reuse the implementation above.
-        other.overlap(self)
-    }
-}
-
-unsafe impl IndexOverlap<Range<usize>> for RangeFrom<usize> {
-    #[inline]
-    fn overlap(&self, other: &Range<usize>) -> bool {
-        // NOTE: reuse the implementation above.
-        other.overlap(self)
-    }
-}
-
-unsafe impl IndexOverlap<RangeInclusive<usize>> for RangeFrom<usize> {
-    #[inline]
-    fn overlap(&self, other: &RangeInclusive<usize>) -> bool {
-        // NOTE: reuse the implementation above.
-        other.overlap(self)
-    }
-}
-
-unsafe impl IndexOverlap<RangeFrom<usize>> for RangeFrom<usize> {
-    #[inline]
-    fn overlap(&self, _other: &RangeFrom<usize>) -> bool {
-        true
-    }
-}
-
-unsafe impl IndexOverlap<RangeTo<usize>> for RangeFrom<usize> {
-    #[inline]
-    fn overlap(&self, other: &RangeTo<usize>) -> bool {
-        self.start < other.end
-    }
-}
-
-unsafe impl IndexOverlap<RangeToInclusive<usize>> for RangeFrom<usize> {
-    #[inline]
-    fn overlap(&self, other: &RangeToInclusive<usize>) -> bool {
-        self.start <= other.end
-    }
-}
-
-/* -------------------------------------------------------------------------- */
-
-unsafe impl IndexOverlap<RangeFull> for RangeTo<usize> {
-    #[inline]
-    fn overlap(&self, other: &RangeFull) -> bool {
-        // NOTE: reuse the implementation above.
-        other.overlap(self)
-    }
-}
-
-unsafe impl IndexOverlap<usize> for RangeTo<usize> {
-    #[inline]
-    fn overlap(&self, other: &usize) -> bool {
-        // NOTE: reuse the implementation above.
-        other.overlap(self)
-    }
-}
-
-unsafe impl IndexOverlap<Range<usize>> for RangeTo<usize> {
-    #[inline]
-    fn overlap(&self, other: &Range<usize>) -> bool {
-        // NOTE: reuse the implementation above.
-        other.overlap(self)
-    }
-}
-
-unsafe impl IndexOverlap<RangeInclusive<usize>> for RangeTo<usize> {
-    #[inline]
-    fn overlap(&self, other: &RangeInclusive<usize>) -> bool {
-        // NOTE: reuse the implementation above.
-        other.overlap(self)
-    }
-}
-
-unsafe impl IndexOverlap<RangeFrom<usize>> for RangeTo<usize> {
-    #[inline]
-    fn overlap(&self, other: &RangeFrom<usize>) -> bool {
-        // NOTE: reuse the implementation above.
-        other.overlap(self)
-    }
-}
-
-unsafe impl IndexOverlap<RangeTo<usize>> for RangeTo<usize> {
-    #[inline]
-    fn overlap(&self, other: &RangeTo<usize>) -> bool {
-        // If one of the range's `end` is `0`, then it is empty and will borrow no item.
-        // If it borrow no item, it cannot overlap.
-        self.end != 0 && other.end != 0
+/// Returns `true` if two spans share an item.
+///
+/// Empty spans borrow no item, so they cannot overlap.
+#[inline]
+pub(crate) const fn spans_overlap(a: &Span, b: &Span) -> bool {
+    const fn starts_before_end(start: usize, span: &Span) -> bool {
+        match span.end {
+            Some(end) => start < end,
+            None => true,
+        }
     }
-}
 
-unsafe impl IndexOverlap<RangeToInclusive<usize>> for RangeTo<usize> {
-    #[inline]
-    fn overlap(&self, _other: &RangeToInclusive<usize>) -> bool {
-        // If `RangeTo::end` is `0`, it is empty and borrow no item.
-        self.end != 0
-    }
+    !a.is_empty() && !b.is_empty() && starts_before_end(a.start, b) && starts_before_end(b.start, a)
 }
 
 /* -------------------------------------------------------------------------- */
 
-unsafe impl IndexOverlap<RangeFull> for RangeToInclusive<usize> {
-    #[inline]
-    fn overlap(&self, other: &RangeFull) -> bool {
-        // NOTE: reuse the implementation above.
-        other.overlap(self)
-    }
-}
-
-unsafe impl IndexOverlap<usize> for RangeToInclusive<usize> {
-    #[inline]
-    fn overlap(&self, other: &usize) -> bool {
-        // NOTE: reuse the implementation above.
-        other.overlap(self)
-    }
-}
-
-unsafe impl IndexOverlap<Range<usize>> for RangeToInclusive<usize> {
-    #[inline]
-    fn overlap(&self, other: &Range<usize>) -> bool {
-        // NOTE: reuse the implementation above.
-        other.overlap(self)
-    }
-}
+// NOTE: a group of indices overlaps another index if any of its indices does,
+// so the overlap of nested groups is checked across all their leaves.
+// As for `SliceSingleIndex`, the `ManyIndices` bound on tuples tells them apart from a pair of bounds.
 
-unsafe impl IndexOverlap<RangeInclusive<usize>> for RangeToInclusive<usize> {
+unsafe impl<I, J, const N: usize> IndexOverlap<J> for [I; N]
+where
+    I: IndexOverlap<J>,
+{
     #[inline]
-    fn overlap(&self, other: &RangeInclusive<usize>) -> bool {
-        // NOTE: reuse the implementation above.
-        other.overlap(self)
+    fn overlap(&self, other: &J) -> bool {
+        self.iter().any(|idx| idx.overlap(other))
     }
 }
 
-unsafe impl IndexOverlap<RangeFrom<usize>> for RangeToInclusive<usize> {
+unsafe impl<I, J, const N: usize> IndexOverlap<[J; N]> for I
+where
+    I: IndexSpan,
+    [J; N]: IndexOverlap<I>,
+{
     #[inline]
-    fn overlap(&self, other: &RangeFrom<usize>) -> bool {
-        // NOTE: reuse the implementation above.
+    fn overlap(&self, other: &[J; N]) -> bool {
         other.overlap(self)
     }
 }
 
-unsafe impl IndexOverlap<RangeTo<usize>> for RangeToInclusive<usize> {
+unsafe impl<I, J, const N: usize> IndexOverlap<[J; N]> for Shared<I>
+where
+    [J; N]: IndexOverlap<Shared<I>>,
+{
     #[inline]
-    fn overlap(&self, other: &RangeTo<usize>) -> bool {
-        // NOTE: reuse the implementation above.
+    fn overlap(&self, other: &[J; N]) -> bool {
         other.overlap(self)
     }
 }
 
-unsafe impl IndexOverlap<RangeToInclusive<usize>> for RangeToInclusive<usize> {
-    #[inline]
-    fn overlap(&self, _other: &RangeToInclusive<usize>) -> bool {
-        true
-    }
-}
+macro_rules! impl_tuple {
+    ( $($I:ident)+ ) => {
+        #[allow(non_snake_case)]
+        unsafe impl<$($I,)* J> IndexOverlap<J> for ($($I,)*)
+        where
+            $( $I: ManyIndices + IndexOverlap<J>, )*
+        {
+            #[inline]
+            fn overlap(&self, other: &J) -> bool {
+                let ( $( $I, )* ) = self;
+
+                false $( || $I.overlap(other) )*
+            }
+        }
+
+        unsafe impl<$($I,)* I> IndexOverlap<($($I,)*)> for I
+        where
+            I: IndexSpan,
+            $( $I: ManyIndices, )*
+            ($($I,)*): IndexOverlap<I>,
+        {
+            #[inline]
+            fn overlap(&self, other: &($($I,)*)) -> bool {
+                other.overlap(self)
+            }
+        }
+
+        unsafe impl<$($I,)* I> IndexOverlap<($($I,)*)> for Shared<I>
+        where
+            $( $I: ManyIndices, )*
+            ($($I,)*): IndexOverlap<Shared<I>>,
+        {
+            #[inline]
+            fn overlap(&self, other: &($($I,)*)) -> bool {
+                other.overlap(self)
+            }
+        }
+    };
+}
+
+for_each_tuple!(impl_tuple);
 
 /* -------------------------------------------------------------------------- */
 
 #[cfg(test)]
 mod tests {
-    // TODO
+    use std::ops::Bound;
+
+    use super::{IndexOverlap, IndexSpan, Span};
+
+    #[test]
+    fn overlap() {
+        assert!(3.overlap(&3));
+        assert!(!3.overlap(&4));
+        assert!(3.overlap(&(2..4)));
+        assert!(!4.overlap(&(2..4)));
+        assert!(4.overlap(&(2..=4)));
+        assert!((2..5).overlap(&(4..)));
+        assert!(!(2..5).overlap(&(5..)));
+        assert!((..=5).overlap(&(5..)));
+        assert!((..).overlap(&7));
+        assert!((Bound::Excluded(2), Bound::Included(4)).overlap(&4));
+        assert!(!(Bound::Excluded(2), Bound::Included(4)).overlap(&2));
+    }
+
+    #[test]
+    fn span() {
+        assert_eq!(3.span(), Span::from_range(&(3..4)));
+        assert_eq!((2..=4).span(), Span::from_range(&(2..5)));
+        assert_eq!(
+            usize::MAX.span(),
+            Span {
+                start: usize::MAX,
+                end: None
+            }
+        );
+        assert_eq!((..=usize::MAX).span(), (..).span());
+        assert!((5..5).span().is_empty());
+        assert!(!(usize::MAX..).span().is_empty());
+    }
+
+    #[test]
+    fn unbounded_overlap() {
+        assert!(usize::MAX.overlap(&usize::MAX));
+        assert!((usize::MAX..).overlap(&(usize::MAX..)));
+        assert!(usize::MAX.overlap(&(usize::MAX..)));
+        assert!((Bound::Excluded(usize::MAX - 1), Bound::Unbounded).overlap(&usize::MAX));
+        assert!((5..=usize::MAX).overlap(&(usize::MAX..)));
+        assert!((5..).overlap(&usize::MAX));
+        assert!(!(usize::MAX - 2).overlap(&(usize::MAX - 1)));
+
+        assert!(!(usize::MAX - 1).overlap(&usize::MAX));
+        assert!(!(0..usize::MAX).overlap(&(usize::MAX..)));
+        assert!(!(..usize::MAX - 1).overlap(&(usize::MAX..)));
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn empty_overlap() {
+        assert!(!(3..3).overlap(&(3..5)));
+        assert!(!(3..5).overlap(&(4..4)));
+        assert!(!(3..3).overlap(&(3..3)));
+        assert!(!(..).overlap(&(4..4)));
+        assert!(!(..0).overlap(&0));
+        assert!(!(5..2).overlap(&(0..10)));
+        assert!(!(Bound::Excluded(3), Bound::Excluded(4)).overlap(&(..)));
+    }
 }
 
 /* -------------------------------------------------------------------------- */
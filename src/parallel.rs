@@ -0,0 +1,39 @@
+use std::panic;
+use std::thread;
+
+use crate::{DisjointIndices, ManyIndices, SliceExt, SliceSingleIndex};
+
+/* -------------------------------------------------------------------------- */
+
+/// Calls `f` on each part of `slice` borrowed by `indices`, in parallel with scoped threads.
+///
+/// Returns the results in the order of the indices,
+/// or [`None`] if `indices` is not in bounds.
+///
+/// # Panics
+///
+/// Panics if `f` panics for any of the parts, once all the threads are done.
+pub fn par_map_many_mut<'slice, T, I, F, R, const N: usize>(
+    slice: &'slice mut [T],
+    indices: DisjointIndices<[I; N]>,
+    f: F,
+) -> Option<[R; N]>
+where
+    [I; N]: ManyIndices,
+    I: SliceSingleIndex<'slice, [T], Output: Send>,
+    F: Fn(I::Output) -> R + Sync,
+    R: Send,
+{
+    let parts = slice.get_many_mut_poc(indices)?;
+    let f = &f;
+
+    let results = thread::scope(|s| {
+        parts
+            .map(|part| s.spawn(move || f(part)))
+            .map(|handle| handle.join())
+    });
+
+    Some(results.map(|result| result.unwrap_or_else(|err| panic::resume_unwind(err))))
+}
+
+/* -------------------------------------------------------------------------- */
@@ -0,0 +1,247 @@
+use std::slice;
+
+use crate::{AnyIndex, DisjointIndices, IndexList, IndexSet, IndexSpan, Span};
+
+/* -------------------------------------------------------------------------- */
+
+// NOTE: the operations work on the spans of the indices. Their result is normalised: sorted,
+// without empty spans and with adjacent spans merged.
+
+impl<I: IndexList> DisjointIndices<I> {
+    /// Returns the indices of both sets, or [`None`] if an index of `self` overlaps an index of `other`.
+    pub fn union<J: IndexList>(
+        &self,
+        other: &DisjointIndices<J>,
+    ) -> Option<DisjointIndices<IndexSet>> {
+        let a = normalized_spans(self.get_ref().as_index_slice());
+        let b = normalized_spans(other.get_ref().as_index_slice());
+
+        if !intersect(&a, &b).is_empty() {
+            return None;
+        }
+
+        let mut spans = a;
+        spans.extend(b);
+
+        Some(from_spans(merge(spans)))
+    }
+
+    /// Returns the items borrowed by both sets.
+    pub fn intersection<J: IndexList>(
+        &self,
+        other: &DisjointIndices<J>,
+    ) -> DisjointIndices<IndexSet> {
+        let a = normalized_spans(self.get_ref().as_index_slice());
+        let b = normalized_spans(other.get_ref().as_index_slice());
+
+        from_spans(intersect(&a, &b))
+    }
+
+    /// Returns the items borrowed by `self` but not by `other`.
+    pub fn difference<J: IndexList>(
+        &self,
+        other: &DisjointIndices<J>,
+    ) -> DisjointIndices<IndexSet> {
+        let a = normalized_spans(self.get_ref().as_index_slice());
+        let b = normalized_spans(other.get_ref().as_index_slice());
+
+        from_spans(subtract(&a, &b))
+    }
+
+    /// Returns the items of a slice of length `len` that are not borrowed by `self`.
+    pub fn complement(&self, len: usize) -> DisjointIndices<IndexSet> {
+        let spans = normalized_spans(self.get_ref().as_index_slice());
+
+        from_spans(subtract(
+            slice::from_ref(&Span::from_range(&(0..len))),
+            &spans,
+        ))
+    }
+
+    /// Returns the same items, with adjacent ranges merged into a single one.
+    pub fn merge_adjacent(&self) -> DisjointIndices<IndexSet> {
+        from_spans(normalized_spans(self.get_ref().as_index_slice()))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn normalized_spans<I: IndexSpan>(indices: &[I]) -> Vec<Span> {
+    merge(indices.iter().map(IndexSpan::span).collect())
+}
+
+/// Returns `true` if `span` reaches `point`, so that a span starting at `point` overlaps or touches it.
+fn reaches(span: &Span, point: usize) -> bool {
+    span.end.is_none_or(|end| point <= end)
+}
+
+/// Sorts `spans`, removes the empty ones and merges the overlapping or adjacent ones.
+fn merge(mut spans: Vec<Span>) -> Vec<Span> {
+    spans.retain(|span| !span.is_empty());
+    spans.sort_unstable_by_key(|span| span.start);
+
+    let mut merged: Vec<Span> = Vec::with_capacity(spans.len());
+    for span in spans {
+        match merged.last_mut() {
+            Some(last) if reaches(last, span.start) => {
+                if span.ends_after(last) {
+                    last.end = span.end;
+                }
+            }
+            _ => merged.push(span),
+        }
+    }
+
+    merged
+}
+
+/// Returns the intersection of two normalised lists of spans.
+fn intersect(a: &[Span], b: &[Span]) -> Vec<Span> {
+    let mut result = Vec::new();
+    let (mut i, mut j) = (0, 0);
+
+    while i < a.len() && j < b.len() {
+        let a_ends_after = a[i].ends_after(&b[j]);
+
+        let span = Span {
+            start: a[i].start.max(b[j].start),
+            end: if a_ends_after { b[j].end } else { a[i].end },
+        };
+        if !span.is_empty() {
+            result.push(span);
+        }
+
+        if a_ends_after {
+            j += 1;
+        } else {
+            i += 1;
+        }
+    }
+
+    result
+}
+
+/// Returns the spans of `a` without the items of `b`, both being normalised.
+fn subtract(a: &[Span], b: &[Span]) -> Vec<Span> {
+    let mut result = Vec::new();
+    let mut j = 0;
+
+    for span in a {
+        // NOTE: `start` becomes `None` once an unbounded span of `b` has removed the rest of `span`.
+        let mut start = Some(span.start);
+
+        // NOTE: skip the spans of `b` that end before this one starts.
+        while j < b.len() && b[j].end.is_some_and(|end| end <= span.start) {
+            j += 1;
+        }
+
+        let mut k = j;
+        while let Some(current) = start {
+            if k == b.len() || !span.end.is_none_or(|end| b[k].start < end) {
+                break;
+            }
+
+            if current < b[k].start {
+                result.push(Span::from_range(&(current..b[k].start)));
+            }
+            start = b[k].end.map(|end| current.max(end));
+            k += 1;
+        }
+
+        if let Some(start) = start {
+            let rest = Span {
+                start,
+                end: span.end,
+            };
+            if !rest.is_empty() {
+                result.push(rest);
+            }
+        }
+    }
+
+    result
+}
+
+fn from_spans(spans: Vec<Span>) -> DisjointIndices<IndexSet> {
+    let indices = spans
+        .into_iter()
+        .map(|span| match span.end {
+            Some(end) => AnyIndex::from(span.start..end),
+            None => AnyIndex::from(span.start..),
+        })
+        .collect();
+
+    // SAFETY: the spans are merged, so they are disjoint.
+    unsafe { DisjointIndices::new_unchecked(IndexSet(indices)) }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use crate::{DisjointIndices, IndexSet};
+
+    fn set(s: &str) -> DisjointIndices<IndexSet> {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn union() {
+        let a = DisjointIndices::new([0..2, 6..8]).unwrap();
+        let b = DisjointIndices::new(vec![2, 9]).unwrap();
+
+        assert_eq!(a.union(&b).unwrap().to_string(), "0..3, 6..8, 9..10");
+        assert!(a.union(&set("7..")).is_none());
+    }
+
+    #[test]
+    fn intersection() {
+        let a = set("0..4, 6..");
+        let b = set("2..7, 9..12");
+
+        assert_eq!(a.intersection(&b).to_string(), "2..4, 6..7, 9..12");
+        assert_eq!(b.intersection(&a).to_string(), "2..4, 6..7, 9..12");
+    }
+
+    #[test]
+    fn difference() {
+        let a = set("0..10, 12..");
+        let b = set("2..4, 5, 9..13");
+
+        assert_eq!(a.difference(&b).to_string(), "0..2, 4..5, 6..9, 13..");
+        assert_eq!(b.difference(&a).to_string(), "10..12");
+    }
+
+    #[test]
+    fn complement() {
+        let a = set("2..4, 5, 8..");
+
+        assert_eq!(a.complement(10).to_string(), "0..2, 4..5, 6..8");
+        assert_eq!(a.complement(3).to_string(), "0..2");
+        assert_eq!(set("..").complement(10).to_string(), "");
+    }
+
+    #[test]
+    fn merge_adjacent() {
+        let a = DisjointIndices::new([4..6, 0..2, 2..4, 7..7, 8..9]).unwrap();
+
+        assert_eq!(a.merge_adjacent().to_string(), "0..6, 8..9");
+    }
+
+    #[test]
+    fn unbounded() {
+        let max = usize::MAX;
+        let a = DisjointIndices::new([max..]).unwrap();
+        let b = set(&format!("0..{max}"));
+
+        assert_eq!(a.merge_adjacent().to_string(), format!("{max}.."));
+        assert_eq!(a.union(&b).unwrap().to_string(), "0..");
+        assert_eq!(a.intersection(&set("5..")).to_string(), format!("{max}.."));
+        assert_eq!(set("5..").difference(&a).to_string(), format!("5..{max}"));
+        assert_eq!(a.difference(&b).to_string(), format!("{max}.."));
+        assert_eq!(b.difference(&set("3..")).to_string(), "0..3");
+        assert!(a.union(&DisjointIndices::new([max]).unwrap()).is_none());
+    }
+}
+
+/* -------------------------------------------------------------------------- */
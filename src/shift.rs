@@ -0,0 +1,445 @@
+use std::ops::{
+    Bound, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
+};
+
+use crate::disjoint::{is_exhausted, resolve_bounds};
+use crate::{DisjointIndices, IndexList, IndexSet, ManyIndices, Mut, Shared, SortedIndices};
+
+/* -------------------------------------------------------------------------- */
+
+/// Moves indices between the coordinates of a slice and those of one of its sub-slices.
+///
+/// Indices with an unbounded end keep borrowing up to the end of the slice,
+/// so they are only rebased onto a sub-slice which also has an unbounded end.
+///
+/// ```
+/// # use slice_get_many_poc::{DisjointIndices, ShiftIndices};
+/// let indices = DisjointIndices::new((0, 2..4)).unwrap();
+///
+/// let parent = indices.offset(10).unwrap();
+/// assert_eq!(parent.get_ref(), &(10, 12..14));
+///
+/// let child = parent.clone().rebase(10..14).unwrap();
+/// assert_eq!(child.get_ref(), &(0, 2..4));
+///
+/// assert!(parent.rebase(11..14).is_none());
+/// ```
+pub trait ShiftIndices {
+    type Output;
+
+    /// Moves the indices of a sub-slice starting at `k` into the coordinates of the whole slice,
+    /// or returns [`None`] if an index overflows.
+    fn offset(self, k: usize) -> Option<Self::Output>;
+
+    /// Moves the indices of a slice into the coordinates of its sub-slice `range`,
+    /// or returns [`None`] if an index borrows items outside of `range`.
+    fn rebase<R: RangeBounds<usize>>(self, range: R) -> Option<Self::Output>;
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The bounds of a sub-slice, `end` being `None` if it goes up to the end of the slice.
+#[derive(Clone, Copy)]
+struct SubRange {
+    start: usize,
+    end: Option<usize>,
+}
+
+impl SubRange {
+    fn new<R: RangeBounds<usize>>(range: &R) -> Option<Self> {
+        let (start, end) = resolve_bounds(range)?;
+
+        Some(Self { start, end })
+    }
+
+    /// Rebases the included start of an index.
+    fn start(self, start: usize) -> Option<usize> {
+        start.checked_sub(self.start)
+    }
+
+    /// Rebases the excluded end of an index.
+    fn end(self, end: usize) -> Option<usize> {
+        match self.end {
+            Some(sub_end) if end > sub_end => None,
+            _ => end.checked_sub(self.start),
+        }
+    }
+
+    /// Rejects an unbounded end if the sub-slice does not go up to the end of the slice.
+    fn unbounded_end(self) -> Option<()> {
+        self.end.is_none().then_some(())
+    }
+}
+
+/// Creates the inclusive range `start..=end`, which is empty right after `end` if `exhausted`.
+fn inclusive(start: usize, end: usize, exhausted: bool) -> Option<RangeInclusive<usize>> {
+    if exhausted {
+        Some(end.checked_add(1)?..=end)
+    } else {
+        Some(start..=end)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl ShiftIndices for usize {
+    type Output = usize;
+
+    fn offset(self, k: usize) -> Option<Self::Output> {
+        self.checked_add(k)
+    }
+
+    fn rebase<R: RangeBounds<usize>>(self, range: R) -> Option<Self::Output> {
+        let range = SubRange::new(&range)?;
+        range.end(self.checked_add(1)?)?;
+        range.start(self)
+    }
+}
+
+impl ShiftIndices for Range<usize> {
+    type Output = Range<usize>;
+
+    fn offset(self, k: usize) -> Option<Self::Output> {
+        Some(self.start.checked_add(k)?..self.end.checked_add(k)?)
+    }
+
+    fn rebase<R: RangeBounds<usize>>(self, range: R) -> Option<Self::Output> {
+        let range = SubRange::new(&range)?;
+        Some(range.start(self.start)?..range.end(self.end)?)
+    }
+}
+
+impl ShiftIndices for RangeInclusive<usize> {
+    type Output = RangeInclusive<usize>;
+
+    fn offset(self, k: usize) -> Option<Self::Output> {
+        let start = self.start().checked_add(k)?;
+        let end = self.end().checked_add(k)?;
+        inclusive(start, end, is_exhausted(&self))
+    }
+
+    fn rebase<R: RangeBounds<usize>>(self, range: R) -> Option<Self::Output> {
+        let range = SubRange::new(&range)?;
+        let start = range.start(*self.start())?;
+        let end = range.end(self.end().checked_add(1)?)?.checked_sub(1)?;
+        inclusive(start, end, is_exhausted(&self))
+    }
+}
+
+impl ShiftIndices for RangeFrom<usize> {
+    type Output = RangeFrom<usize>;
+
+    fn offset(self, k: usize) -> Option<Self::Output> {
+        Some(self.start.checked_add(k)?..)
+    }
+
+    fn rebase<R: RangeBounds<usize>>(self, range: R) -> Option<Self::Output> {
+        let range = SubRange::new(&range)?;
+        range.unbounded_end()?;
+        Some(range.start(self.start)?..)
+    }
+}
+
+impl ShiftIndices for RangeTo<usize> {
+    type Output = Range<usize>;
+
+    fn offset(self, k: usize) -> Option<Self::Output> {
+        (0..self.end).offset(k)
+    }
+
+    fn rebase<R: RangeBounds<usize>>(self, range: R) -> Option<Self::Output> {
+        (0..self.end).rebase(range)
+    }
+}
+
+impl ShiftIndices for RangeToInclusive<usize> {
+    type Output = RangeInclusive<usize>;
+
+    fn offset(self, k: usize) -> Option<Self::Output> {
+        (0..=self.end).offset(k)
+    }
+
+    fn rebase<R: RangeBounds<usize>>(self, range: R) -> Option<Self::Output> {
+        (0..=self.end).rebase(range)
+    }
+}
+
+impl ShiftIndices for RangeFull {
+    type Output = RangeFrom<usize>;
+
+    fn offset(self, k: usize) -> Option<Self::Output> {
+        (0..).offset(k)
+    }
+
+    fn rebase<R: RangeBounds<usize>>(self, range: R) -> Option<Self::Output> {
+        (0..).rebase(range)
+    }
+}
+
+impl ShiftIndices for (Bound<usize>, Bound<usize>) {
+    type Output = (Bound<usize>, Bound<usize>);
+
+    fn offset(self, k: usize) -> Option<Self::Output> {
+        let start = match self.0 {
+            Bound::Included(start) => Bound::Included(start.checked_add(k)?),
+            Bound::Excluded(start) => Bound::Excluded(start.checked_add(k)?),
+            Bound::Unbounded => Bound::Included(k),
+        };
+        let end = match self.1 {
+            Bound::Included(end) => Bound::Included(end.checked_add(k)?),
+            Bound::Excluded(end) => Bound::Excluded(end.checked_add(k)?),
+            Bound::Unbounded => Bound::Unbounded,
+        };
+
+        Some((start, end))
+    }
+
+    fn rebase<R: RangeBounds<usize>>(self, range: R) -> Option<Self::Output> {
+        let range = SubRange::new(&range)?;
+
+        let start = match self.0 {
+            Bound::Included(start) => range.start(start)?,
+            Bound::Excluded(start) => range.start(start.checked_add(1)?)?,
+            Bound::Unbounded => range.start(0)?,
+        };
+        let end = match self.1 {
+            Bound::Included(end) => {
+                Bound::Included(range.end(end.checked_add(1)?)?.checked_sub(1)?)
+            }
+            Bound::Excluded(end) => Bound::Excluded(range.end(end)?),
+            Bound::Unbounded => {
+                range.unbounded_end()?;
+                Bound::Unbounded
+            }
+        };
+
+        Some((Bound::Included(start), end))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl<I: ShiftIndices, const N: usize> ShiftIndices for [I; N] {
+    type Output = [I::Output; N];
+
+    fn offset(self, k: usize) -> Option<Self::Output> {
+        self.try_map(|idx| idx.offset(k))
+    }
+
+    fn rebase<R: RangeBounds<usize>>(self, range: R) -> Option<Self::Output> {
+        let range = (range.start_bound().cloned(), range.end_bound().cloned());
+        self.try_map(|idx| idx.rebase(range))
+    }
+}
+
+impl<I: ShiftIndices> ShiftIndices for Vec<I> {
+    type Output = Vec<I::Output>;
+
+    fn offset(self, k: usize) -> Option<Self::Output> {
+        self.into_iter().map(|idx| idx.offset(k)).collect()
+    }
+
+    fn rebase<R: RangeBounds<usize>>(self, range: R) -> Option<Self::Output> {
+        let range = (range.start_bound().cloned(), range.end_bound().cloned());
+        self.into_iter().map(|idx| idx.rebase(range)).collect()
+    }
+}
+
+impl<I: ShiftIndices + Clone> ShiftIndices for &[I] {
+    type Output = Vec<I::Output>;
+
+    fn offset(self, k: usize) -> Option<Self::Output> {
+        self.iter().cloned().map(|idx| idx.offset(k)).collect()
+    }
+
+    fn rebase<R: RangeBounds<usize>>(self, range: R) -> Option<Self::Output> {
+        let range = (range.start_bound().cloned(), range.end_bound().cloned());
+        self.iter().cloned().map(|idx| idx.rebase(range)).collect()
+    }
+}
+
+impl ShiftIndices for IndexSet {
+    type Output = IndexSet;
+
+    fn offset(self, k: usize) -> Option<Self::Output> {
+        self.0.offset(k).map(IndexSet)
+    }
+
+    fn rebase<R: RangeBounds<usize>>(self, range: R) -> Option<Self::Output> {
+        self.0.rebase(range).map(IndexSet)
+    }
+}
+
+macro_rules! impl_tuple {
+    ( $($I:ident)+ ) => {
+        #[allow(non_snake_case)]
+        impl<$($I: ShiftIndices,)*> ShiftIndices for ($($I,)*) {
+            type Output = ( $( $I::Output, )* );
+
+            fn offset(self, k: usize) -> Option<Self::Output> {
+                let ( $( $I, )* ) = self;
+                Some(( $( $I.offset(k)?, )* ))
+            }
+
+            fn rebase<R: RangeBounds<usize>>(self, range: R) -> Option<Self::Output> {
+                let range = (range.start_bound().cloned(), range.end_bound().cloned());
+                let ( $( $I, )* ) = self;
+                Some(( $( $I.rebase(range)?, )* ))
+            }
+        }
+    };
+}
+
+for_each_tuple!(impl_tuple);
+
+/* -------------------------------------------------------------------------- */
+
+macro_rules! impl_marker {
+    ( $($Marker:ident)* ) => {$(
+        impl<I: ShiftIndices> ShiftIndices for $Marker<I> {
+            type Output = $Marker<I::Output>;
+
+            fn offset(self, k: usize) -> Option<Self::Output> {
+                self.0.offset(k).map($Marker)
+            }
+
+            fn rebase<R: RangeBounds<usize>>(self, range: R) -> Option<Self::Output> {
+                self.0.rebase(range).map($Marker)
+            }
+        }
+    )*};
+}
+
+impl_marker!(Shared Mut);
+
+// NOTE: shifting moves every index by the same amount, but an end that reaches `usize::MAX`
+// becomes unbounded, so the shifted indices are checked again.
+
+impl<I> ShiftIndices for DisjointIndices<I>
+where
+    I: ShiftIndices,
+    I::Output: ManyIndices,
+{
+    type Output = DisjointIndices<I::Output>;
+
+    fn offset(self, k: usize) -> Option<Self::Output> {
+        DisjointIndices::new(self.get().offset(k)?)
+    }
+
+    fn rebase<R: RangeBounds<usize>>(self, range: R) -> Option<Self::Output> {
+        DisjointIndices::new(self.get().rebase(range)?)
+    }
+}
+
+impl<I> ShiftIndices for SortedIndices<I>
+where
+    I: ShiftIndices,
+    I::Output: IndexList,
+{
+    type Output = SortedIndices<I::Output>;
+
+    fn offset(self, k: usize) -> Option<Self::Output> {
+        SortedIndices::new(self.get().offset(k)?)
+    }
+
+    fn rebase<R: RangeBounds<usize>>(self, range: R) -> Option<Self::Output> {
+        SortedIndices::new(self.get().rebase(range)?)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Bound;
+
+    use crate::{DisjointIndices, ManyIndices, Mut, Shared, SortedIndices};
+
+    use super::ShiftIndices;
+
+    #[test]
+    fn offset() {
+        assert_eq!(3.offset(2), Some(5));
+        assert_eq!((1..4).offset(2), Some(3..6));
+        assert_eq!((1..=4).offset(2), Some(3..=6));
+        assert_eq!((1..).offset(2), Some(3..));
+        assert_eq!((..4).offset(2), Some(2..6));
+        assert_eq!((..=4).offset(2), Some(2..=6));
+        assert_eq!((..).offset(2), Some(2..));
+        assert_eq!(
+            (Bound::Unbounded, Bound::Included(4)).offset(2),
+            Some((Bound::Included(2), Bound::Included(6)))
+        );
+        assert_eq!(
+            (0, Shared(1..2), Mut(5..)).offset(1),
+            Some((1, Shared(2..3), Mut(6..)))
+        );
+
+        assert_eq!(usize::MAX.offset(1), None);
+        assert_eq!(vec![0, usize::MAX - 1].offset(2), None);
+    }
+
+    #[test]
+    fn rebase() {
+        assert_eq!(5.rebase(3..6), Some(2));
+        assert_eq!(6.rebase(3..6), None);
+        assert_eq!(2.rebase(3..6), None);
+        assert_eq!((3..6).rebase(3..6), Some(0..3));
+        assert_eq!((3..7).rebase(3..6), None);
+        assert_eq!((3..=5).rebase(3..=5), Some(0..=2));
+        assert_eq!((4..).rebase(3..), Some(1..));
+        assert_eq!((4..).rebase(3..10), None);
+        assert_eq!((..4).rebase(..6), Some(0..4));
+        assert_eq!((..4).rebase(1..6), None);
+        assert_eq!((..).rebase(..), Some(0..));
+        assert_eq!(
+            (Bound::Excluded(3), Bound::Excluded(6)).rebase(4..6),
+            Some((Bound::Included(0), Bound::Excluded(2)))
+        );
+
+        assert_eq!([10, 12].rebase(10..13), Some([0, 2]));
+        assert_eq!([10, 13].rebase(10..13), None);
+    }
+
+    #[test]
+    fn exhausted_range() {
+        let mut exhausted = 2..=2;
+        exhausted.by_ref().for_each(drop);
+
+        let shifted = exhausted.offset(1).unwrap();
+        assert!(shifted.is_empty());
+        assert!((shifted.clone(), 3).is_disjoint());
+
+        let rebased = shifted.rebase(1..).unwrap();
+        assert!(rebased.is_empty());
+        assert!((rebased, 2).is_disjoint());
+    }
+
+    #[test]
+    fn disjoint() {
+        let indices = DisjointIndices::new([0..2, 2..4]).unwrap();
+
+        assert_eq!(indices.clone().offset(3).unwrap().get(), [3..5, 5..7]);
+        assert_eq!(indices.clone().rebase(..4).unwrap().get(), [0..2, 2..4]);
+        assert!(indices.rebase(1..).is_none());
+
+        // NOTE: the empty pair of bounds becomes unbounded once its start overflows.
+        let empty = (
+            Bound::Excluded(usize::MAX - 1),
+            Bound::Excluded(usize::MAX - 1),
+        );
+        let indices = DisjointIndices::new((empty, usize::MAX - 1..)).unwrap();
+        assert!(indices.clone().rebase(1..).is_some());
+        assert!(indices.offset(1).is_none());
+    }
+
+    #[test]
+    fn sorted() {
+        let indices = SortedIndices::new(vec![0..2, 1..4]).unwrap();
+
+        assert_eq!(indices.clone().offset(3).unwrap().get(), [3..5, 4..7]);
+        assert!(indices.rebase(1..).is_none());
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -1,7 +1,11 @@
 /* -------------------------------------------------------------------------- */
 
 use std::mem;
+use std::ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
 use std::slice::SliceIndex;
+use std::str;
+
+use crate::ManyIndices;
 
 pub trait SliceManyIndices<'slice, Slice: ?Sized> {
     type Output;
@@ -12,29 +16,169 @@ pub trait SliceManyIndices<'slice, Slice: ?Sized> {
     ///
     /// Calling this method with overlapping or out-of-bounds indices is *undefined behavior*
     /// even if the resulting references are not used.
+    /// For `str`, so is calling it with indices that are not on UTF-8 char boundaries.
     unsafe fn get_many_unchecked_mut(self, slice: &'slice mut Slice) -> Self::Output;
 }
 
+/// A set of indices which gets its items from a raw pointer to the slice.
+///
+/// This allows containers that are not a single slice, like [`VecDeque`](std::collections::VecDeque),
+/// to hand out their items through a short-lived view of their storage.
+/// It is not exported, so only the sets of indices of this crate implement it.
+pub trait RawManyIndices<'slice, Slice: ?Sized>: SliceManyIndices<'slice, Slice> {
+    /// Returns mutable references to many indices at once from a raw pointer to the slice,
+    /// without doing any checks.
+    ///
+    /// # Safety
+    ///
+    /// The items `slice` gives access to must be valid for `'slice` and not borrowed by any other live reference.
+    /// Calling this method with overlapping or out-of-bounds indices is *undefined behavior*
+    /// even if the resulting references are not used.
+    /// For `str`, so is calling it with indices that are not on UTF-8 char boundaries.
+    unsafe fn get_many_unchecked_raw(self, slice: *mut Slice) -> Self::Output;
+}
+
+/// A single index of a set of indices, which gets its item from a raw pointer to the slice.
+///
+/// This allows a set of indices to hand out its items without holding a reference to the whole slice.
+///
+/// # Safety
+///
+/// `get_unchecked_raw` must only access the items the index borrows,
+/// and only mutably access them if the index borrows them mutably.
+pub unsafe trait SliceSingleIndex<'slice, Slice: ?Sized> {
+    type Output;
+
+    /// Returns a reference to the item(s) at this index, without doing any checks.
+    ///
+    /// # Safety
+    ///
+    /// The items `slice` gives access to must be valid for `'slice`, the index must be in bounds (and on UTF-8 char boundaries for `str`)
+    /// and the items it borrows must not be borrowed by any other live reference, unless both are shared.
+    unsafe fn get_unchecked_raw(self, slice: *mut Slice) -> Self::Output;
+}
+
+/* -------------------------------------------------------------------------- */
+
+macro_rules! impl_single_index {
+    ( $($I:ty),* ) => {$(
+        unsafe impl<'slice, T: 'slice> SliceSingleIndex<'slice, [T]> for $I {
+            type Output = &'slice mut <$I as SliceIndex<[T]>>::Output;
+
+            #[inline]
+            unsafe fn get_unchecked_raw(self, slice: *mut [T]) -> Self::Output {
+                // SAFETY: contract uphold by the caller.
+                unsafe { &mut *slice.get_unchecked_mut(self) }
+            }
+        }
+    )*};
+
+    (@str $($I:ty),* ) => {$(
+        unsafe impl<'slice> SliceSingleIndex<'slice, str> for $I {
+            type Output = &'slice mut str;
+
+            #[inline]
+            unsafe fn get_unchecked_raw(self, slice: *mut str) -> Self::Output {
+                // NOTE: we cannot get raw pointers into a `str`, so we go through its bytes.
+                let bytes = slice as *mut [u8];
+
+                // SAFETY: contract uphold by the caller, the indices lie on char boundaries.
+                unsafe { str::from_utf8_unchecked_mut(&mut *bytes.get_unchecked_mut(self)) }
+            }
+        }
+    )*};
+}
+
+impl_single_index!(
+    usize,
+    Range<usize>,
+    RangeInclusive<usize>,
+    RangeFrom<usize>,
+    RangeTo<usize>,
+    RangeToInclusive<usize>,
+    RangeFull,
+    (Bound<usize>, Bound<usize>)
+);
+
+impl_single_index!(@str
+    Range<usize>,
+    RangeInclusive<usize>,
+    RangeFrom<usize>,
+    RangeTo<usize>,
+    RangeToInclusive<usize>,
+    RangeFull,
+    (Bound<usize>, Bound<usize>)
+);
+
+/* -------------------------------------------------------------------------- */
+
+// NOTE: a group of indices can itself be an index of a set, its output being nested the same way.
+// The `ManyIndices` bound on the indices of a tuple tells it apart from a pair of bounds.
+
+unsafe impl<'slice, Slice: ?Sized, I, const N: usize> SliceSingleIndex<'slice, Slice> for [I; N]
+where
+    I: SliceSingleIndex<'slice, Slice>,
+{
+    type Output = [I::Output; N];
+
+    #[inline]
+    unsafe fn get_unchecked_raw(self, slice: *mut Slice) -> Self::Output {
+        // SAFETY: contract uphold by the caller, for every index of the group.
+        self.map(|idx| unsafe { idx.get_unchecked_raw(slice) })
+    }
+}
+
+macro_rules! impl_single_tuple {
+    ( $($I:ident)+ ) => {
+        #[allow(non_snake_case)]
+        unsafe impl<'slice, Slice: ?Sized, $($I,)*> SliceSingleIndex<'slice, Slice> for ($($I,)*)
+        where
+            $( $I: ManyIndices + SliceSingleIndex<'slice, Slice>, )*
+        {
+            type Output = ( $( $I::Output, )* );
+
+            #[inline]
+            unsafe fn get_unchecked_raw(self, slice: *mut Slice) -> Self::Output {
+                let ( $( $I, )* ) = self;
+
+                // SAFETY: contract uphold by the caller, for every index of the group.
+                unsafe { ( $( $I.get_unchecked_raw(slice), )* ) }
+            }
+        }
+    };
+}
+
+for_each_tuple!(impl_single_tuple);
+
 /* -------------------------------------------------------------------------- */
 
-impl<'slice, T: 'slice, I, const N: usize> SliceManyIndices<'slice, [T]> for [I; N]
+impl<'slice, Slice: ?Sized, I, const N: usize> SliceManyIndices<'slice, Slice> for [I; N]
 where
-    I: SliceIndex<[T], Output: 'slice>,
+    I: SliceSingleIndex<'slice, Slice>,
 {
-    type Output = [&'slice mut I::Output; N];
+    type Output = [I::Output; N];
+
+    unsafe fn get_many_unchecked_mut(self, slice: &'slice mut Slice) -> Self::Output {
+        // SAFETY: contract uphold by the caller.
+        unsafe { self.get_many_unchecked_raw(slice) }
+    }
+}
 
-    unsafe fn get_many_unchecked_mut(self, slice: &'slice mut [T]) -> Self::Output {
+impl<'slice, Slice: ?Sized, I, const N: usize> RawManyIndices<'slice, Slice> for [I; N]
+where
+    I: SliceSingleIndex<'slice, Slice>,
+{
+    unsafe fn get_many_unchecked_raw(self, slice: *mut Slice) -> Self::Output {
         // NOTE: adapted from [T]::get_many_unchecked_mut, I did not ensures this code is 100% safe or 100% optimal.
 
         {
-            let slice: *mut [T] = slice;
-            let mut arr: mem::MaybeUninit<[&'slice mut I::Output; N]> = mem::MaybeUninit::uninit();
-            let arr_ptr = arr.as_mut_ptr();
+            let mut arr: mem::MaybeUninit<[I::Output; N]> = mem::MaybeUninit::uninit();
+            let arr_ptr = arr.as_mut_ptr().cast::<I::Output>();
 
             // SAFETY: Caller ensure the indices are all in bounds and didn't overlap.
             unsafe {
                 for (i, idx) in self.into_iter().enumerate() {
-                    *(*arr_ptr).get_unchecked_mut(i) = &mut *slice.get_unchecked_mut(idx);
+                    arr_ptr.add(i).write(idx.get_unchecked_raw(slice));
                 }
 
                 arr.assume_init()
@@ -45,20 +189,80 @@ where
 
 /* -------------------------------------------------------------------------- */
 
+impl<'slice, Slice: ?Sized, I> SliceManyIndices<'slice, Slice> for Vec<I>
+where
+    I: SliceSingleIndex<'slice, Slice>,
+{
+    type Output = Vec<I::Output>;
+
+    unsafe fn get_many_unchecked_mut(self, slice: &'slice mut Slice) -> Self::Output {
+        // SAFETY: contract uphold by the caller.
+        unsafe { self.get_many_unchecked_raw(slice) }
+    }
+}
+
+impl<'slice, Slice: ?Sized, I> RawManyIndices<'slice, Slice> for Vec<I>
+where
+    I: SliceSingleIndex<'slice, Slice>,
+{
+    unsafe fn get_many_unchecked_raw(self, slice: *mut Slice) -> Self::Output {
+        // SAFETY: Caller ensure the indices are all in bounds and didn't overlap.
+        self.into_iter()
+            .map(|idx| unsafe { idx.get_unchecked_raw(slice) })
+            .collect()
+    }
+}
+
+impl<'slice, Slice: ?Sized, I> SliceManyIndices<'slice, Slice> for &[I]
+where
+    I: SliceSingleIndex<'slice, Slice> + Clone,
+{
+    type Output = Vec<I::Output>;
+
+    unsafe fn get_many_unchecked_mut(self, slice: &'slice mut Slice) -> Self::Output {
+        // SAFETY: contract uphold by the caller.
+        unsafe { self.get_many_unchecked_raw(slice) }
+    }
+}
+
+impl<'slice, Slice: ?Sized, I> RawManyIndices<'slice, Slice> for &[I]
+where
+    I: SliceSingleIndex<'slice, Slice> + Clone,
+{
+    unsafe fn get_many_unchecked_raw(self, slice: *mut Slice) -> Self::Output {
+        // SAFETY: Caller ensure the indices are all in bounds and didn't overlap.
+        self.iter()
+            .cloned()
+            .map(|idx| unsafe { idx.get_unchecked_raw(slice) })
+            .collect()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 macro_rules! impl_tuple {
     ( $($I:ident)+ ) => {
-        #[allow(non_snake_case)]
-        impl<'slice, T, $($I,)*> SliceManyIndices<'slice, [T]> for ($($I,)*)
+        impl<'slice, Slice: ?Sized, $($I,)*> SliceManyIndices<'slice, Slice> for ($($I,)*)
         where
-            $( $I: SliceIndex<[T], Output: 'slice>, )*
+            $( $I: SliceSingleIndex<'slice, Slice>, )*
         {
-            type Output = ( $( &'slice mut $I::Output, )* );
+            type Output = ( $( $I::Output, )* );
+
+            unsafe fn get_many_unchecked_mut(self, slice: &'slice mut Slice) -> Self::Output {
+                // SAFETY: contract uphold by the caller.
+                unsafe { self.get_many_unchecked_raw(slice) }
+            }
+        }
 
-            unsafe fn get_many_unchecked_mut(self, slice: &'slice mut [T]) -> Self::Output {
+        #[allow(non_snake_case)]
+        impl<'slice, Slice: ?Sized, $($I,)*> RawManyIndices<'slice, Slice> for ($($I,)*)
+        where
+            $( $I: SliceSingleIndex<'slice, Slice>, )*
+        {
+            unsafe fn get_many_unchecked_raw(self, slice: *mut Slice) -> Self::Output {
                 // NOTE: adapted from [T]::get_many_unchecked_mut, I did not ensures this code is 100% safe or 100% optimal.
 
                 {
-                    let slice: *mut [T] = slice;
                     let ( $( $I, )* ) = self;
 
                     // SAFETY: We expect the indices to contain disjunct values that are
@@ -66,24 +270,16 @@ macro_rules! impl_tuple {
                     unsafe {
                         (
                             $(
-                                &mut *slice.get_unchecked_mut($I),
+                                $I.get_unchecked_raw(slice),
                             )*
                         )
                     }
                 }
             }
         }
-
-        impl_tuple!(@recursive $($I)*);
-    };
-
-    () => {/* stop condition */};
-
-    (@recursive $_:ident $($I:ident)*) => {
-        impl_tuple!($($I)*);
     };
 }
 
-impl_tuple!(I0 I1 I2 I3 I4 I5 I6 I7 I8 I9 I10 I11);
+for_each_tuple!(impl_tuple);
 
 /* -------------------------------------------------------------------------- */
@@ -0,0 +1,207 @@
+use std::ops;
+
+use crate::disjoint::find_overlap_in_order;
+use crate::error::GetManyError;
+use crate::overlap::{IndexOverlap, IndexSpan};
+use crate::slice_index::RawManyIndices;
+use crate::{ManyIndices, SliceManyIndices};
+
+/* -------------------------------------------------------------------------- */
+
+/// Asserts that the indices in `I` are sorted by ascending start.
+///
+/// Checking that sorted indices are disjoint only costs `O(n)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SortedIndices<I>(I);
+
+impl<I> SortedIndices<I> {
+    /// # Safety
+    ///
+    /// The indices in `I` must be sorted by ascending start of their [`IndexSpan`].
+    pub unsafe fn new_unchecked(indices: I) -> Self {
+        Self(indices)
+    }
+
+    pub fn get(self) -> I {
+        self.0
+    }
+
+    pub fn get_ref(&self) -> &I {
+        &self.0
+    }
+}
+
+impl<I: IndexList> SortedIndices<I> {
+    pub fn new(indices: I) -> Option<Self> {
+        let is_sorted = indices
+            .as_index_slice()
+            .is_sorted_by_key(|idx| idx.span().start);
+
+        if is_sorted {
+            // SAFETY: `indices` are sorted.
+            Some(unsafe { Self::new_unchecked(indices) })
+        } else {
+            None
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A set of indices stored contiguously.
+///
+/// # Safety
+///
+/// `as_index_slice` must return exactly the indices the set borrows through its
+/// [`SliceManyIndices`] implementations, and the same ones on every call.
+pub unsafe trait IndexList {
+    type Index: ManyIndices + IndexOverlap<Self::Index> + IndexSpan;
+
+    fn as_index_slice(&self) -> &[Self::Index];
+}
+
+unsafe impl<I, const N: usize> IndexList for [I; N]
+where
+    I: ManyIndices + IndexOverlap<I> + IndexSpan,
+{
+    type Index = I;
+
+    fn as_index_slice(&self) -> &[I] {
+        self
+    }
+}
+
+unsafe impl<I> IndexList for Vec<I>
+where
+    I: ManyIndices + IndexOverlap<I> + IndexSpan,
+{
+    type Index = I;
+
+    fn as_index_slice(&self) -> &[I] {
+        self
+    }
+}
+
+unsafe impl<I> IndexList for &[I]
+where
+    I: ManyIndices + IndexOverlap<I> + IndexSpan,
+{
+    type Index = I;
+
+    fn as_index_slice(&self) -> &[I] {
+        self
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+unsafe impl<I> ManyIndices for SortedIndices<I>
+where
+    I: ManyIndices + IndexList,
+{
+    fn is_disjoint(&self) -> bool {
+        let indices = self.0.as_index_slice();
+
+        // NOTE: `SortedIndices` asserts that the indices are already in order.
+        indices.iter().all(ManyIndices::is_disjoint)
+            && find_overlap_in_order(indices, 0..indices.len()).is_none()
+    }
+
+    fn is_in_bounds(&self, bounds: ops::RangeTo<usize>) -> bool {
+        self.0.is_in_bounds(bounds)
+    }
+
+    fn check_in_bounds(&self, bounds: ops::RangeTo<usize>) -> Result<(), GetManyError> {
+        self.0.check_in_bounds(bounds)
+    }
+
+    fn check_disjoint_and_in_bounds(
+        &self,
+        bounds: ops::RangeTo<usize>,
+    ) -> Result<(), GetManyError> {
+        self.0.check_in_bounds(bounds)?;
+
+        let indices = self.0.as_index_slice();
+
+        for (i, idx) in indices.iter().enumerate() {
+            idx.check_disjoint_and_in_bounds(bounds)
+                .map_err(|err| err.at(i))?;
+        }
+
+        match find_overlap_in_order(indices, 0..indices.len()) {
+            Some((i, j)) => Err(GetManyError::overlap(i, j, bounds.end)
+                .with_index(indices[i].fmt_index())
+                .with_index(indices[j].fmt_index())),
+            None => Ok(()),
+        }
+    }
+
+    fn check_char_boundaries(&self, s: &str) -> Result<(), GetManyError> {
+        self.0.check_char_boundaries(s)
+    }
+
+    fn fmt_index(&self) -> Option<String> {
+        self.0.fmt_index()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl<'slice, Slice: ?Sized, I> SliceManyIndices<'slice, Slice> for SortedIndices<I>
+where
+    I: SliceManyIndices<'slice, Slice>,
+{
+    type Output = I::Output;
+
+    unsafe fn get_many_unchecked_mut(self, slice: &'slice mut Slice) -> Self::Output {
+        // SAFETY: contract uphold by the caller.
+        unsafe { SliceManyIndices::get_many_unchecked_mut(self.0, slice) }
+    }
+}
+
+impl<'slice, Slice: ?Sized, I> RawManyIndices<'slice, Slice> for SortedIndices<I>
+where
+    I: RawManyIndices<'slice, Slice>,
+{
+    unsafe fn get_many_unchecked_raw(self, slice: *mut Slice) -> Self::Output {
+        // SAFETY: contract uphold by the caller.
+        unsafe { RawManyIndices::get_many_unchecked_raw(self.0, slice) }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+    use crate::{DisjointIndices, GetManyError, ManyIndices};
+
+    use super::SortedIndices;
+
+    #[test]
+    fn sorted_indices() {
+        assert!(SortedIndices::new([0, 5, 9]).is_some());
+        assert!(SortedIndices::new([0, 9, 5]).is_none());
+        assert!(SortedIndices::new(vec![0..2, 2..4, 4..6]).is_some());
+        assert!(SortedIndices::new(&[4..6, 0..4][..]).is_none());
+    }
+
+    #[test]
+    fn sorted_overlap() {
+        let indices = SortedIndices::new([0..10, 11..12, 12..14]).unwrap();
+        assert!(indices.is_disjoint());
+        assert!(DisjointIndices::new(indices).is_some());
+
+        let indices = SortedIndices::new([0..10, 1..2, 3..4]).unwrap();
+        assert!(!indices.is_disjoint());
+
+        let indices = SortedIndices::new([0..10, 10..12, 11..14]).unwrap();
+        assert_eq!(
+            indices.check_disjoint_and_in_bounds(..14),
+            Err(GetManyError::overlap(1, 2, 14)
+                .with_index(Some(String::from("10..12")))
+                .with_index(Some(String::from("11..14"))))
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
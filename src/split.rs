@@ -0,0 +1,248 @@
+use std::{array, iter, mem, slice};
+
+use crate::{DisjointIndices, IndexList, IndexSpan, ManyIndices, SliceManyIndices, SortedIndices};
+
+/* -------------------------------------------------------------------------- */
+
+/// Represents multiple points at which a slice can be cut.
+pub trait SplitPoints<'slice, T: 'slice> {
+    type Output;
+
+    /// Returns `true` if the points are in ascending order.
+    fn is_sorted(&self) -> bool;
+
+    /// Cuts `slice` at each point, returning all the sub-slices in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the points are not in ascending order or not in bounds of `..=slice.len()`.
+    fn split_many_mut(self, slice: &'slice mut [T]) -> Self::Output;
+}
+
+/// Cuts `rest` at `point`, returning the part before it and keeping the part after it in `rest`.
+///
+/// `offset` is the position of `rest` in the original slice.
+fn split_next<'slice, T>(
+    rest: &mut &'slice mut [T],
+    offset: &mut usize,
+    point: usize,
+) -> &'slice mut [T] {
+    let (head, tail) = mem::take(rest).split_at_mut(point - *offset);
+    *rest = tail;
+    *offset = point;
+    head
+}
+
+macro_rules! impl_array {
+    ( $( $N:literal => $M:literal ),* ) => {$(
+        impl<'slice, T: 'slice> SplitPoints<'slice, T> for [usize; $N] {
+            type Output = [&'slice mut [T]; $M];
+
+            fn is_sorted(&self) -> bool {
+                <[usize]>::is_sorted(self)
+            }
+
+            fn split_many_mut(self, slice: &'slice mut [T]) -> Self::Output {
+                let mut rest = slice;
+                let mut offset = 0;
+
+                // NOTE: `array::from_fn` calls the closure in ascending order.
+                array::from_fn(|i| match self.get(i) {
+                    Some(&point) => split_next(&mut rest, &mut offset, point),
+                    None => mem::take(&mut rest),
+                })
+            }
+        }
+    )*};
+}
+
+impl_array!(
+    0 => 1, 1 => 2, 2 => 3, 3 => 4, 4 => 5, 5 => 6, 6 => 7,
+    7 => 8, 8 => 9, 9 => 10, 10 => 11, 11 => 12, 12 => 13
+);
+
+impl<'slice, T: 'slice> SplitPoints<'slice, T> for Vec<usize> {
+    type Output = Vec<&'slice mut [T]>;
+
+    fn is_sorted(&self) -> bool {
+        <[usize]>::is_sorted(self)
+    }
+
+    fn split_many_mut(self, slice: &'slice mut [T]) -> Self::Output {
+        self.as_slice().split_many_mut(slice)
+    }
+}
+
+impl<'slice, T: 'slice> SplitPoints<'slice, T> for &[usize] {
+    type Output = Vec<&'slice mut [T]>;
+
+    fn is_sorted(&self) -> bool {
+        <[usize]>::is_sorted(self)
+    }
+
+    fn split_many_mut(self, slice: &'slice mut [T]) -> Self::Output {
+        let mut rest = slice;
+        let mut offset = 0;
+
+        let mut parts: Vec<_> = self
+            .iter()
+            .map(|&point| split_next(&mut rest, &mut offset, point))
+            .collect();
+        parts.push(rest);
+
+        parts
+    }
+}
+
+macro_rules! impl_tuple {
+    ( $($P:ident)+ ) => {
+        #[allow(non_snake_case)]
+        impl<'slice, T: 'slice> SplitPoints<'slice, T> for ( $( impl_tuple!(@usize $P), )* ) {
+            type Output = ( $( impl_tuple!(@slice $P), )* &'slice mut [T], );
+
+            fn is_sorted(&self) -> bool {
+                let ( $($P,)* ) = *self;
+                <[usize]>::is_sorted(&[ $($P),* ])
+            }
+
+            fn split_many_mut(self, slice: &'slice mut [T]) -> Self::Output {
+                let ( $($P,)* ) = self;
+                let mut rest = slice;
+                let mut offset = 0;
+
+                $(
+                    let $P = split_next(&mut rest, &mut offset, $P);
+                )*
+
+                ( $($P,)* rest, )
+            }
+        }
+    };
+
+    (@usize $_:ident) => { usize };
+    (@slice $_:ident) => { &'slice mut [T] };
+}
+
+for_each_tuple!(impl_tuple);
+
+macro_rules! impl_wrapper {
+    ( $($Wrapper:ident)* ) => {$(
+        impl<'slice, T: 'slice, I> SplitPoints<'slice, T> for $Wrapper<I>
+        where
+            I: SplitPoints<'slice, T>,
+        {
+            type Output = I::Output;
+
+            fn is_sorted(&self) -> bool {
+                self.get_ref().is_sorted()
+            }
+
+            fn split_many_mut(self, slice: &'slice mut [T]) -> Self::Output {
+                self.get().split_many_mut(slice)
+            }
+        }
+    )*};
+}
+
+impl_wrapper!(DisjointIndices SortedIndices);
+
+/* -------------------------------------------------------------------------- */
+
+/// An extension trait to add the `split_at_many_mut` and `get_many_mut_with_gaps` methods to slice.
+pub trait SliceSplitExt<T> {
+    /// Cuts the slice at many points at once, returning all the sub-slices in order,
+    /// or [`None`] if `points` is not sorted or not in bounds.
+    ///
+    /// A point equal to the length of the slice is in bounds.
+    fn split_at_many_mut<'slice, I>(&'slice mut self, points: I) -> Option<I::Output>
+    where
+        T: 'slice,
+        I: ManyIndices + SplitPoints<'slice, T>;
+
+    /// Returns mutable references to many indices at once, along with the non-empty gaps between them,
+    /// or [`None`] if an index is out of bounds.
+    ///
+    /// The gaps are all the items not borrowed by `indices`, in slice order.
+    ///
+    /// Only sets of indices that implement [`IndexList`], like arrays, `Vec` and slices, are accepted:
+    /// tuples are not supported since their indices do not share a single type.
+    /// The gaps are computed from the [`IndexSpan`](crate::IndexSpan) of each index of the list:
+    /// the safety contracts of both traits ensure that they cover every item `indices` borrows.
+    fn get_many_mut_with_gaps<'slice, I>(
+        &'slice mut self,
+        indices: DisjointIndices<I>,
+    ) -> Option<(I::Output, Vec<&'slice mut [T]>)>
+    where
+        T: 'slice,
+        I: IndexList + SliceManyIndices<'slice, [T]>;
+}
+
+impl<T> SliceSplitExt<T> for [T] {
+    fn split_at_many_mut<'slice, I>(&'slice mut self, points: I) -> Option<I::Output>
+    where
+        T: 'slice,
+        I: ManyIndices + SplitPoints<'slice, T>,
+    {
+        // NOTE: the slice can be cut right after its last item.
+        if !points.is_in_bounds(..self.len().saturating_add(1)) || !points.is_sorted() {
+            return None;
+        }
+
+        Some(points.split_many_mut(self))
+    }
+
+    fn get_many_mut_with_gaps<'slice, I>(
+        &'slice mut self,
+        indices: DisjointIndices<I>,
+    ) -> Option<(I::Output, Vec<&'slice mut [T]>)>
+    where
+        T: 'slice,
+        I: IndexList + SliceManyIndices<'slice, [T]>,
+    {
+        let len = self.len();
+
+        let is_in_bounds = indices
+            .get_ref()
+            .as_index_slice()
+            .iter()
+            .all(|idx| idx.is_in_bounds(..len));
+        if !is_in_bounds {
+            return None;
+        }
+
+        let mut spans: Vec<_> = indices
+            .get_ref()
+            .as_index_slice()
+            .iter()
+            .map(|idx| idx.span().to_range(len))
+            .collect();
+        spans.sort_unstable_by_key(|span| span.start);
+
+        // NOTE: the trailing empty span closes the gap after the last index.
+        let mut gaps = Vec::new();
+        let mut gap_start = 0;
+        for span in spans.into_iter().chain(iter::once(len..len)) {
+            if gap_start < span.start {
+                gaps.push(gap_start..span.start);
+            }
+            gap_start = gap_start.max(span.end);
+        }
+
+        let slice: *mut [T] = self;
+
+        // SAFETY: the indices are disjoint and in bounds, and the gaps only cover the items outside
+        // of their spans. `IndexList` ensures that the listed indices are the ones `indices` borrows,
+        // and `IndexSpan` that their spans contain every item they borrow.
+        unsafe {
+            let output = indices.get().get_many_unchecked_mut(&mut *slice);
+            let gaps = gaps
+                .into_iter()
+                .map(|gap| slice::from_raw_parts_mut(slice.as_mut_ptr().add(gap.start), gap.len()))
+                .collect();
+
+            Some((output, gaps))
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
@@ -55,6 +55,188 @@ fn tuple_indices() {
     assert_eq!(array, [0, 1, 6, 3, 4, 15, 6, 70, 80, 9, 10]);
 }
 
+#[test]
+fn bound_indices() {
+    use std::ops::Bound;
+
+    let mut array = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+    let [a, b] = array
+        .get_many_mut_poc([
+            (Bound::Excluded(1), Bound::Included(3)),
+            (Bound::Included(8), Bound::Unbounded),
+        ])
+        .unwrap();
+    assert_eq!(a, [3, 4]);
+    assert_eq!(b, [9, 10]);
+
+    let (a, b) = array
+        .get_many_mut_poc((0, (Bound::Excluded(0), Bound::Excluded(2))))
+        .unwrap();
+    assert_eq!((*a, &*b), (1, &[2][..]));
+
+    assert!(array
+        .get_many_mut_poc([
+            (Bound::Unbounded, Bound::Included(4)),
+            (Bound::Included(4), Bound::Unbounded)
+        ])
+        .is_none());
+    assert!(array
+        .get_many_mut_poc([(Bound::Excluded(usize::MAX), Bound::Unbounded)])
+        .is_none());
+
+    let mut s = String::from("héllo");
+    let [a] = s
+        .as_mut_str()
+        .get_many_mut_poc([(Bound::Included(3), Bound::Unbounded)])
+        .unwrap();
+    assert_eq!(a, "llo");
+    assert!(s
+        .as_mut_str()
+        .get_many_mut_poc([(Bound::Included(2), Bound::Unbounded)])
+        .is_none());
+}
+
+#[test]
+fn custom_indices() {
+    #[derive(Clone, Copy)]
+    struct NodeId(u32);
+
+    unsafe impl AsIndex for NodeId {
+        type Index = usize;
+
+        fn as_index(&self) -> usize {
+            self.0 as usize
+        }
+    }
+
+    #[derive(Clone)]
+    struct Window {
+        offset: usize,
+        range: std::ops::Range<usize>,
+    }
+
+    unsafe impl AsIndex for Window {
+        type Index = std::ops::Range<usize>;
+
+        fn as_index(&self) -> std::ops::Range<usize> {
+            self.offset + self.range.start..self.offset + self.range.end
+        }
+    }
+
+    let mut array = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+    let [a, b] = array.get_many_mut_poc([NodeId(0), NodeId(9)]).unwrap();
+    assert_eq!((*a, *b), (1, 10));
+
+    let window = Window {
+        offset: 4,
+        range: 0..3,
+    };
+    let (a, b, c) = array
+        .get_many_mut_poc((NodeId(0), window.clone(), 8..))
+        .unwrap();
+    assert_eq!((*a, &*b, &*c), (1, &[5, 6, 7][..], &[9, 10][..]));
+
+    assert!(array
+        .get_many_mut_poc((NodeId(5), window.clone()))
+        .is_none());
+    assert!(array
+        .get_many_mut_poc(vec![NodeId(3), NodeId(1), NodeId(3)])
+        .is_none());
+    assert!(array
+        .get_many_mut_poc(vec![
+            window,
+            Window {
+                offset: 8,
+                range: 0..3
+            }
+        ])
+        .is_none());
+}
+
+#[test]
+fn custom_index_set() {
+    struct Ends;
+
+    unsafe impl ManyIndices for Ends {
+        fn is_disjoint(&self) -> bool {
+            true
+        }
+
+        fn is_in_bounds(&self, bounds: std::ops::RangeTo<usize>) -> bool {
+            bounds.end >= 2
+        }
+    }
+
+    impl<'slice, T: 'slice> SliceManyIndices<'slice, [T]> for Ends {
+        type Output = (&'slice mut T, &'slice mut T);
+
+        unsafe fn get_many_unchecked_mut(self, slice: &'slice mut [T]) -> Self::Output {
+            let (first, rest) = slice.split_first_mut().unwrap();
+            (first, rest.last_mut().unwrap())
+        }
+    }
+
+    let mut array = [1, 2, 3, 4];
+
+    let (a, b) = array.get_many_mut_poc(Ends).unwrap();
+    std::mem::swap(a, b);
+    assert_eq!(array, [4, 2, 3, 1]);
+
+    assert!([1].get_many_mut_poc(Ends).is_none());
+}
+
+#[test]
+fn nested_indices() {
+    let mut array = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+    let ((a, b), c) = array.get_many_mut_poc(((0, 1), 2..4)).unwrap();
+    assert_eq!((*a, *b, &*c), (1, 2, &[3, 4][..]));
+
+    let [[a, b], [c, d]] = array.get_many_mut_poc([[0, 1], [8, 9]]).unwrap();
+    assert_eq!((*a, *b, *c, *d), (1, 2, 9, 10));
+
+    let (a, [b, c]) = array
+        .get_many_mut_poc((Shared(..2), [Mut(4), Mut(5)]))
+        .unwrap();
+    assert_eq!((a, *b, *c), (&[1, 2][..], 5, 6));
+
+    assert!(array.get_many_mut_poc(((0, 1), 1..4)).is_none());
+    assert!(array.get_many_mut_poc(([0, 5], (2, [3, 5]))).is_none());
+    assert!(array.get_many_mut_poc([[0, 1], [2, 10]]).is_none());
+    assert!(array.get_many_mut_poc([[0, 0], [1, 2]]).is_none());
+    assert!(array.try_get_many_mut_poc([[3, 4], [5, 5]]).is_err());
+    assert!(DisjointIndices::new([[3, 4], [5, 5]]).is_none());
+
+    let (a, (b, c)) = array
+        .get_many_mut_poc((Shared(..2), (Shared(0), Mut(4))))
+        .unwrap();
+    assert_eq!((a, b, *c), (&[1, 2][..], &1, 5));
+
+    let mut array = [0; 14];
+    let (first, second) = array
+        .get_many_mut_poc(((0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11), (12, 13)))
+        .unwrap();
+    *first.11 = 1;
+    *second.1 = 2;
+    assert_eq!(array[11..], [1, 0, 2]);
+}
+
+#[test]
+fn shared_and_mut_indices() {
+    let mut array = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+    let (a, b, c) = array
+        .get_many_mut_poc((Mut(2), Shared(8), Shared(5..9)))
+        .unwrap();
+    *a = *b + c.iter().sum::<i32>();
+
+    assert_eq!(array, [0, 1, 34, 3, 4, 5, 6, 7, 8, 9, 10]);
+
+    assert!(array.get_many_mut_poc((Mut(6), Shared(5..9))).is_none());
+}
+
 #[test]
 fn checked_distinct_indices() {
     let mut array = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
@@ -71,3 +253,448 @@ fn checked_distinct_indices() {
         assert_eq!(items, (&mut 0, &mut [5, 6, 7, 8, 9] as &mut [_]));
     }
 }
+
+#[test]
+fn const_distinct_indices() {
+    let mut array = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+    let items = array.get_many_mut_poc(disjoint_indices![0, 3, 7]).unwrap();
+    assert_eq!(items, [&mut 0, &mut 3, &mut 7]);
+
+    let items = array
+        .get_many_mut_poc(disjoint_indices![0..2, 9..11])
+        .unwrap();
+    assert_eq!(items, [&mut [0, 1], &mut [9, 10]]);
+
+    const END: usize = 10;
+    let items = array
+        .get_many_mut_poc(disjoint_indices![0..=1, 2..(END - 1), 9..=END])
+        .unwrap();
+    assert_eq!(items.map(|items| items.len()), [2, 7, 2]);
+
+    let items = array
+        .get_many_mut_poc(disjoint_indices![END - 1, END])
+        .unwrap();
+    assert_eq!(items, [&mut 9, &mut 10]);
+}
+
+#[test]
+fn const_array_indices() {
+    let mut array = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+    let [a, b] = array.get_many_mut_const(const_indices!([2..5, 5..11]));
+    a[0] = 20;
+    b[5] = 100;
+
+    let (a, b, c) = array.get_many_mut_const(const_indices!((0, 1..2, 10)));
+    *a = 10;
+    b[0] = 11;
+    *c += 1;
+
+    assert_eq!(array, [10, 11, 20, 3, 4, 5, 6, 7, 8, 9, 101]);
+}
+
+#[test]
+fn parallel_indices() {
+    let mut array: Vec<u64> = (0..100).collect();
+
+    let indices = DisjointIndices::new([0..25, 25..50, 50..75, 75..100]).unwrap();
+    let sums = par_map_many_mut(&mut array, indices, |part| {
+        for x in part.iter_mut() {
+            *x *= 2;
+        }
+        part.iter().sum::<u64>()
+    })
+    .unwrap();
+
+    assert_eq!(sums, [600, 1850, 3100, 4350]);
+    assert_eq!(array[99], 198);
+
+    let indices = DisjointIndices::new([0..50, 50..101]).unwrap();
+    assert!(par_map_many_mut(&mut array, indices, |part| part.len()).is_none());
+}
+
+#[test]
+fn split_at_many_points() {
+    let mut array = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+    let [a, b, c, d] = array.split_at_many_mut([3, 7, 9]).unwrap();
+    assert_eq!(
+        (&*a, &*b, &*c, &*d),
+        (&[1, 2, 3][..], &[4, 5, 6, 7][..], &[8, 9][..], &[10][..])
+    );
+    a[0] = 0;
+    d[0] = 0;
+
+    let (a, b, c) = array.split_at_many_mut((0, 10)).unwrap();
+    assert_eq!((a.len(), b.len(), c.len()), (0, 10, 0));
+    assert_eq!((b[0], b[9]), (0, 0));
+
+    let parts = array.split_at_many_mut(vec![2, 2, 5]).unwrap();
+    assert_eq!(
+        parts.iter().map(|p| p.len()).collect::<Vec<_>>(),
+        [2, 0, 3, 5]
+    );
+
+    let points = SortedIndices::new([4, 8]).unwrap();
+    let [_, b, _] = array.split_at_many_mut(points).unwrap();
+    assert_eq!(b, [5, 6, 7, 8]);
+
+    assert!(array.split_at_many_mut([7, 3]).is_none());
+    assert!(array.split_at_many_mut([3, 11]).is_none());
+}
+
+#[test]
+fn indices_with_gaps() {
+    let mut array = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+    let indices = DisjointIndices::new([6..8, 2..4]).unwrap();
+    let ([a, b], gaps) = array.get_many_mut_with_gaps(indices).unwrap();
+    assert_eq!((&*a, &*b), (&[7, 8][..], &[3, 4][..]));
+    assert_eq!(gaps, [&[1, 2][..], &[5, 6][..], &[9, 10][..]]);
+    for gap in gaps {
+        gap.fill(0);
+    }
+    a[0] = 70;
+
+    assert_eq!(array, [0, 0, 3, 4, 0, 0, 70, 8, 0, 0]);
+
+    let indices = DisjointIndices::new(vec![0, 9]).unwrap();
+    let (_, gaps) = array.get_many_mut_with_gaps(indices).unwrap();
+    assert_eq!(gaps, [&[0, 3, 4, 0, 0, 70, 8, 0][..]]);
+
+    let indices = DisjointIndices::new([5..10, 0..5]).unwrap();
+    let (_, gaps) = array.get_many_mut_with_gaps(indices).unwrap();
+    assert!(gaps.is_empty());
+
+    let indices = DisjointIndices::new([0..2, 8..11]).unwrap();
+    assert!(array.get_many_mut_with_gaps(indices).is_none());
+}
+
+#[test]
+fn parsed_indices() {
+    let mut array = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+    let indices: DisjointIndices<IndexSet> = "0, 3..5, 8..".parse().unwrap();
+    assert_eq!(indices.to_string(), "0, 3..5, 8..");
+
+    let parts = array.get_many_mut_poc(indices).unwrap();
+    assert_eq!(parts[0], AnyOutput::Item(&mut 1));
+    assert_eq!(parts[1], AnyOutput::Slice(&mut [4, 5]));
+    assert_eq!(parts[2], AnyOutput::Slice(&mut [9, 10]));
+
+    let indices: IndexSet = "2..=3, 11".parse().unwrap();
+    assert!(array.get_many_mut_poc(indices).is_none());
+
+    let err = "0, 3..5, 4"
+        .parse::<DisjointIndices<IndexSet>>()
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "index at column 10 overlaps a previous index"
+    );
+}
+
+#[test]
+fn complement_indices() {
+    let mut array = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+    let regions = DisjointIndices::new([2..4, 4..5, 8..10]).unwrap();
+    let rest = regions.complement(array.len());
+    assert_eq!(regions.merge_adjacent().to_string(), "2..5, 8..10");
+
+    for part in array.get_many_mut_poc(rest).unwrap() {
+        part.into_slice().fill(0);
+    }
+
+    assert_eq!(array, [0, 0, 3, 4, 5, 0, 0, 0, 9, 10]);
+}
+
+#[test]
+fn shifted_indices() {
+    let mut array = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+    let parent = DisjointIndices::new((4, 6..8)).unwrap();
+    let child = parent.clone().rebase(4..).unwrap();
+    assert_eq!(child.clone().offset(4), Some(parent));
+
+    let (a, b) = array[4..].get_many_mut_poc(child).unwrap();
+    assert_eq!((*a, &*b), (5, &[7, 8][..]));
+
+    let parent = DisjointIndices::new((4, 6..8)).unwrap();
+    assert!(parent.rebase(5..).is_none());
+}
+
+#[test]
+fn each_indices() {
+    let mut array = [1, 2, 3, 4, 5];
+
+    let [left, center, right] = array.get_each_mut_poc([3, 4, 5]).unwrap();
+    assert_eq!((left, center, right), (Some(&mut 4), Some(&mut 5), None));
+
+    let (a, b, c) = array.get_each_mut_poc((0, 1..3, 4..)).unwrap();
+    assert_eq!(
+        (a, b, c),
+        (Some(&mut 1), Some(&mut [2, 3][..]), Some(&mut [5][..]))
+    );
+
+    let parts = array.get_each_mut_poc(vec![0..2, 4..6]).unwrap();
+    assert_eq!(parts, [Some(&mut [1, 2][..]), None]);
+
+    assert!(array.get_each_mut_poc([4, 4]).is_none());
+    assert!(array.get_each_mut_poc((3..9, 6)).is_none());
+
+    let mut s = String::from("héllo");
+    let [a, b] = s.as_mut_str().get_each_mut_poc([0..2, 3..5]).unwrap();
+    assert_eq!((a, b.as_deref()), (None, Some("ll")));
+}
+
+#[test]
+fn optional_indices() {
+    let mut array = [1, 2, 3, 4, 5];
+
+    let [a, b, c] = array.get_many_mut_poc([Some(0), None, Some(4)]).unwrap();
+    assert_eq!((a, b, c), (Some(&mut 1), None, Some(&mut 5)));
+
+    let (a, b) = array.get_many_mut_poc((None::<usize>, Some(1..3))).unwrap();
+    assert_eq!((a, b), (None, Some(&mut [2, 3][..])));
+
+    let parts = array.get_many_mut_poc(vec![None, Some(2..), None]).unwrap();
+    assert_eq!(parts, [None, Some(&mut [3, 4, 5][..]), None]);
+
+    assert!(array.get_many_mut_poc([Some(1), None, Some(1)]).is_none());
+    assert!(array.get_many_mut_poc([None, Some(5)]).is_none());
+}
+
+#[test]
+fn any_indices() {
+    let mut array = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+    let indices: Vec<AnyIndex> = vec![0.into(), (2..4).into(), (5..=6).into(), (8..).into()];
+    let parts = array.get_many_mut_poc(indices.clone()).unwrap();
+    assert_eq!(parts[0], AnyOutput::Item(&mut 1));
+    assert_eq!(parts[3], AnyOutput::Slice(&mut [9, 10]));
+
+    for part in parts {
+        part.into_slice().fill(0);
+    }
+    assert_eq!(array, [0, 2, 0, 0, 5, 0, 0, 8, 0, 0]);
+
+    let indices = SortedIndices::new(indices).unwrap();
+    assert!(array.get_many_mut_poc(indices).is_some());
+
+    let indices: Vec<AnyIndex> = vec![(..3).into(), 2.into()];
+    assert!(array.get_many_mut_poc(indices).is_none());
+
+    let indices: Vec<AnyIndex> = vec![(..).into(), (10..10).into()];
+    assert!(array.get_many_mut_poc(indices).is_some());
+}
+
+#[test]
+fn sorted_indices() {
+    let mut array = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+    let indices = SortedIndices::new([0..2, 3..5, 5..6]).unwrap();
+    let items = array.get_many_mut_poc(indices).unwrap();
+    assert_eq!(items, [&mut [0, 1][..], &mut [3, 4], &mut [5]]);
+
+    let indices = SortedIndices::new(vec![1, 4, 4]).unwrap();
+    assert!(array.get_many_mut_poc(indices).is_none());
+}
+
+#[test]
+fn dynamic_indices() {
+    let mut array = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+    let neighbours = vec![6, 4, 9];
+    let items = array.get_many_mut_poc(neighbours).unwrap();
+    assert_eq!(items, [&mut 6, &mut 4, &mut 9]);
+
+    for x in items {
+        *x *= 10;
+    }
+
+    let ranges = [0..2, 7..9];
+    let items = array.get_many_mut_poc(&ranges[..]).unwrap();
+    assert_eq!(items, [&mut [0, 1], &mut [7, 8]]);
+
+    assert_eq!(array, [0, 1, 2, 3, 40, 5, 60, 7, 8, 90, 10]);
+
+    assert!(array.get_many_mut_poc(vec![1..5, 9..11, 4..6]).is_none());
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn rejected_indices() {
+    let mut array = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+    let err = array.try_get_many_mut_poc([2, 11]).unwrap_err();
+    assert_eq!(err.kind(), GetManyErrorKind::OutOfBounds { position: 1 });
+    assert_eq!(err.slice_len(), 11);
+
+    let err = array.try_get_many_mut_poc((2..5, 7, 4)).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        GetManyErrorKind::Overlap {
+            first: 0,
+            second: 2
+        }
+    );
+
+    let err = array.try_get_many_mut_poc([1..3, 6..4]).unwrap_err();
+    assert_eq!(err.kind(), GetManyErrorKind::InvalidRange { position: 1 });
+
+    let indices = DisjointIndices::new([0, 12]).unwrap();
+    let err = array.try_get_many_mut_poc(indices).unwrap_err();
+    assert_eq!(err.kind(), GetManyErrorKind::OutOfBounds { position: 1 });
+}
+
+#[test]
+fn str_indices() {
+    let mut s = String::from("hello, wörld!");
+
+    let [a, b] = s.get_many_mut_poc([0..5, 7..13]).unwrap();
+    assert_eq!((&*a, &*b), ("hello", "wörld"));
+    a.make_ascii_uppercase();
+    b.make_ascii_uppercase();
+    assert_eq!(s, "HELLO, WöRLD!");
+
+    let (a, b) = s.get_many_mut_poc((..1, 7..=13)).unwrap();
+    assert_eq!((&*a, &*b), ("H", "WöRLD!"));
+
+    // `ö` is two bytes long, starting at byte 8.
+    let err = s.try_get_many_mut_poc((0..5, 7..9)).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        GetManyErrorKind::NotCharBoundary { position: 1 }
+    );
+    assert!(s.get_many_mut_poc((9.., ..3)).is_none());
+}
+
+#[test]
+fn index_many() {
+    let mut array = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+
+    let (a, b) = array.index_many_mut_poc((0, 3..5));
+    *a = 10;
+    b[1] = 40;
+
+    assert_eq!(array, [10, 1, 2, 3, 40, 5, 6, 7, 8, 9, 10]);
+}
+
+#[test]
+#[should_panic(expected = "index 11 at position 1 out of range for slice of length 11")]
+fn index_many_out_of_bounds() {
+    let mut array = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    array.index_many_mut_poc([0, 11]);
+}
+
+#[test]
+#[should_panic(expected = "indices 2..5 at position 0 and 4 at position 2 overlap")]
+fn index_many_overlap() {
+    let mut array = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    array.index_many_mut_poc((2..5, 7, 4));
+}
+
+#[test]
+#[should_panic(expected = "range 2..4 at position 1 is not on a char boundary of str of length 6")]
+fn index_many_str() {
+    let mut s = String::from("wörld");
+    s.index_many_mut_poc((0..1, 2..4));
+}
+
+#[test]
+fn deque_indices() {
+    use std::collections::VecDeque;
+
+    // NOTE: pushing to the front wraps the storage, `0, 1, 2` lie at its end.
+    let mut deque: VecDeque<i32> = (3..8).collect();
+    for i in (0..3).rev() {
+        deque.push_front(i);
+    }
+    assert!(!deque.as_slices().1.is_empty());
+
+    let (a, (front, back), c) = deque.get_many_mut_poc((0, 1..6, 7)).unwrap();
+    *a = 10;
+    *c = 70;
+    for item in front.iter_mut().chain(back.iter_mut()) {
+        *item *= 10;
+    }
+
+    assert_eq!(deque, [10, 10, 20, 30, 40, 50, 6, 70]);
+
+    let [(front, back)] = deque.get_many_mut_poc([..]).unwrap();
+    assert_eq!(front.len() + back.len(), 8);
+
+    let err = deque.try_get_many_mut_poc((2..5, 4)).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        GetManyErrorKind::Overlap {
+            first: 0,
+            second: 1
+        }
+    );
+    assert!(deque.get_many_mut_poc([8]).is_none());
+}
+
+#[test]
+fn owned_indices() {
+    let mut vec = vec![0, 1, 2, 3, 4, 5];
+    let (a, b) = vec.get_many_mut_poc((1, 3..)).unwrap();
+    *a = 10;
+    b[0] = 30;
+    assert_eq!(vec, [0, 10, 2, 30, 4, 5]);
+    assert!(vec.get_many_mut_poc([1, 1]).is_none());
+
+    let mut boxed: Box<[i32]> = vec.into_boxed_slice();
+    let [a, b] = boxed.get_many_mut_poc([0, 5]).unwrap();
+    std::mem::swap(a, b);
+    assert_eq!(*boxed, [5, 10, 2, 30, 4, 0]);
+    assert!(boxed.get_many_mut_poc([0, 6]).is_none());
+
+    let (a, b) = boxed.try_get_many_mut_poc((0, 1..)).unwrap();
+    *a += b.iter().sum::<i32>();
+    assert_eq!(*boxed, [51, 10, 2, 30, 4, 0]);
+
+    let mut s = String::from("hello world");
+    let (a, b) = s.get_many_mut_poc((..5, 6..)).unwrap();
+    a.make_ascii_uppercase();
+    b.make_ascii_uppercase();
+    assert_eq!(s, "HELLO WORLD");
+
+    let mut s = String::from("héllo");
+    assert!(s.get_many_mut_poc((0..2,)).is_none());
+}
+
+#[test]
+fn index_many_location() {
+    use std::panic;
+    use std::sync::{Arc, Mutex};
+
+    const MESSAGE: &str = "index 7 at position 0 out of range for slice of length 3";
+    static LOCATION: Mutex<Option<(String, u32)>> = Mutex::new(None);
+
+    // NOTE: the hook is shared with the tests running alongside, so it only records our panic
+    // and hands the others to the previous hook.
+    let previous = Arc::new(panic::take_hook());
+    panic::set_hook(Box::new({
+        let previous = Arc::clone(&previous);
+        move |info| match info.payload_as_str() {
+            Some(MESSAGE) => {
+                let location = info.location().unwrap();
+                *LOCATION.lock().unwrap() = Some((location.file().to_owned(), location.line()));
+            }
+            _ => previous(info),
+        }
+    }));
+
+    let line = line!() + 1;
+    let result = panic::catch_unwind(|| [0, 1, 2].index_many_mut_poc([7]).map(|item| *item));
+    panic::set_hook(Box::new(move |info| previous(info)));
+
+    assert!(result.is_err());
+    assert_eq!(
+        LOCATION.lock().unwrap().take(),
+        Some((file!().to_owned(), line))
+    );
+}